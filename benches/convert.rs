@@ -0,0 +1,147 @@
+//! Benchmarks of the conversion hot path.
+//!
+//! Run with `cargo bench --bench convert`.
+//!
+//! A plain timing loop without the statistics of `criterion`: each benchmark runs `ROUNDS`
+//! times and reports the median with the fastest and slowest runs, so only large differences
+//! between two runs of the bench are meaningful.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lich_ta::{
+    convert_date_to_lichta, day_can_chi, lunar_holiday, solar_term_starting, year_calendar,
+    Converter, Date, SequentialConverter, Timezone,
+};
+
+const TIMEZONE: f64 = Timezone::VIETNAM.hours();
+const ROUNDS: u32 = 20;
+
+/// Every day of a Gregorian year.
+fn year_dates(year: i32) -> Vec<Date> {
+    let mut date = Date::from_calendar_date(year, time::Month::January, 1).unwrap();
+    let mut dates = Vec::new();
+    while date.year() == year {
+        dates.push(date);
+        date = date.next_day().unwrap();
+    }
+    dates
+}
+
+/// Time `f` over [`ROUNDS`] runs after a warm-up run, returning the median run.
+fn measure(name: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up
+    f();
+    let mut samples: Vec<Duration> = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+    let median = samples[samples.len() / 2];
+    let (fastest, slowest) = (samples[0], samples[samples.len() - 1]);
+    println!("{name:<40} {median:>12.2?} per year ({fastest:.2?} to {slowest:.2?})");
+    median
+}
+
+fn bench_convert_date_to_lichta(year: i32) -> Duration {
+    let dates = year_dates(year);
+    measure(&format!("convert_date_to_lichta ({year})"), || {
+        for &date in &dates {
+            black_box(convert_date_to_lichta(black_box(date), TIMEZONE));
+        }
+    })
+}
+
+fn bench_converter(year: i32) -> Duration {
+    let dates = year_dates(year);
+    measure(&format!("Converter::convert ({year})"), || {
+        let mut converter = Converter::new(TIMEZONE);
+        for &date in &dates {
+            black_box(converter.convert(black_box(date)));
+        }
+    })
+}
+
+/// Time `baseline` and `f` in turns over [`ROUNDS`] runs each, returning the median of the
+/// speedups of `f` in each round.
+///
+/// Alternating the runs keeps drifts of the machine, e.g. of its clock frequency, out of the
+/// comparison.
+fn compare(name: &str, mut baseline: impl FnMut(), mut f: impl FnMut()) -> f64 {
+    let time = |f: &mut dyn FnMut()| {
+        let start = Instant::now();
+        f();
+        start.elapsed().as_secs_f64()
+    };
+    // Warm up
+    baseline();
+    f();
+    let mut speedups: Vec<f64> = (0..ROUNDS)
+        .map(|_| time(&mut baseline) / time(&mut f))
+        .collect();
+    speedups.sort_by(f64::total_cmp);
+    let median = speedups[speedups.len() / 2];
+    let (lowest, highest) = (speedups[0], speedups[speedups.len() - 1]);
+    println!("{name:<40} {median:>11.2}x ({lowest:.2}x to {highest:.2}x)");
+    median
+}
+
+fn bench_sequential_converter(year: i32) -> f64 {
+    let dates = year_dates(year);
+    compare(
+        &format!("SequentialConverter speedup ({year})"),
+        || {
+            let mut converter = Converter::new(TIMEZONE);
+            for &date in &dates {
+                black_box(converter.convert(black_box(date)));
+            }
+        },
+        || {
+            let mut converter = SequentialConverter::new(TIMEZONE);
+            for &date in &dates {
+                black_box(converter.next(black_box(date)));
+            }
+        },
+    )
+}
+
+fn bench_per_day_calendar(year: i32) -> Duration {
+    let dates = year_dates(year);
+    measure(&format!("per-day calendar calls ({year})"), || {
+        for &date in &dates {
+            let ngay_ta = lich_ta::NgayTa::from_date(black_box(date), TIMEZONE);
+            black_box(day_can_chi(date));
+            black_box(solar_term_starting(date, TIMEZONE));
+            black_box(lunar_holiday(&ngay_ta));
+        }
+    })
+}
+
+fn bench_year_calendar(year: i32) -> Duration {
+    measure(&format!("year_calendar ({year})"), || {
+        black_box(year_calendar(black_box(year), TIMEZONE));
+    })
+}
+
+fn main() {
+    // 2024 is a common lunar year, 2025 has a leap month
+    bench_convert_date_to_lichta(2024);
+    bench_converter(2024);
+    let uncached = bench_convert_date_to_lichta(2025);
+    let cached = bench_converter(2025);
+    println!(
+        "Converter speedup in a leap year: {:.2}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+    bench_sequential_converter(2025);
+
+    let per_day = bench_per_day_calendar(2025);
+    let single_pass = bench_year_calendar(2025);
+    println!(
+        "year_calendar speedup: {:.2}x",
+        per_day.as_secs_f64() / single_pass.as_secs_f64()
+    );
+}
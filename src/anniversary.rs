@@ -0,0 +1,183 @@
+//! Lunar anniversaries over Gregorian dates: death anniversaries (giỗ) and birthdays.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::util::{date_from_julian_day, get_lunar_month_span};
+use crate::{Date, NgayTa};
+
+/// Get every date in `start..=end` on which a lunar anniversary falls.
+///
+/// The anniversary is kept on the same day of the same lunar month each year, with these
+/// fallbacks:
+/// - Day 30 falls on day 29 in years where the month only has 29 days.
+/// - A leap-month anniversary falls in the regular month of the same number in years
+///   without that leap month. A regular-month anniversary is never repeated in a leap month.
+///
+/// No dates are returned if `lunar_month` is not in `1..=12` or `lunar_day` is not in `1..=30`.
+///
+/// Parameters:
+/// - `lunar_day`: Day of the lunar month.
+/// - `lunar_month`: Lunar month.
+/// - `leap`: Whether the lunar month is a leap month.
+/// - `start`: First Gregorian date of the range.
+/// - `end`: Last Gregorian date of the range.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn anniversaries_in_range(
+    lunar_day: i32,
+    lunar_month: i32,
+    leap: bool,
+    start: Date,
+    end: Date,
+    timezone: f64,
+) -> Vec<Date> {
+    let mut dates = Vec::new();
+    if !(1..=12).contains(&lunar_month) || !(1..=30).contains(&lunar_day) || start > end {
+        return dates;
+    }
+    // Lunar year `n` runs from Tết of `n` to Tết of `n + 1`, so it ends before February of `n + 1`
+    for year in start.year() - 1..=end.year() {
+        let span = leap
+            .then(|| get_lunar_month_span(lunar_month, year, true, timezone).ok())
+            .flatten()
+            .or_else(|| get_lunar_month_span(lunar_month, year, false, timezone).ok());
+        let Some((month_start, month_length)) = span else {
+            continue;
+        };
+        let day = lunar_day.min(month_length);
+        if let Some(date) = date_from_julian_day(month_start + f64::from(day - 1)) {
+            if start <= date && date <= end {
+                dates.push(date);
+            }
+        }
+    }
+    dates
+}
+
+/// Get the years in which a Gregorian birthday falls on the lunar birthday.
+///
+/// The lunar birthday follows the same fallbacks as [`anniversaries_in_range`]. The two
+/// calendars often line up again after 19 years, so a person sees the coincidence a few times
+/// in a lifetime. The year of birth is returned too when it is in `years`. A birthday on
+/// February 29 is only looked for in leap years.
+///
+/// Parameters:
+/// - `birth`: Gregorian date of birth.
+/// - `years`: Gregorian years to search, e.g. `birth.year()..=birth.year() + 100`.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: (Gregorian year, birthday) pairs in the order of the years.
+///
+/// # Panics
+///
+/// Panics if `birth` is outside the range supported by [`Date`].
+pub fn solar_birthday_matches_lunar(
+    birth: Date,
+    years: RangeInclusive<i32>,
+    timezone: f64,
+) -> Vec<(i32, Date)> {
+    let lunar_birth = NgayTa::from_date(birth, timezone);
+    years
+        .filter_map(|year| {
+            let birthday = Date::from_calendar_date(year, birth.month(), birth.day()).ok()?;
+            let lunar_birthdays = anniversaries_in_range(
+                lunar_birth.day(),
+                lunar_birth.month(),
+                lunar_birth.is_leap_month(),
+                birthday,
+                birthday,
+                timezone,
+            );
+            (!lunar_birthdays.is_empty()).then_some((year, birthday))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_anniversaries_in_range() {
+        // 17/4 in 2024 and 2025
+        let dates = anniversaries_in_range(
+            17,
+            4,
+            false,
+            date(2024, Month::January, 1),
+            date(2025, Month::December, 31),
+            7.0,
+        );
+        assert_eq!(dates.len(), 2);
+        assert_eq!(dates[0], date(2024, Month::May, 24));
+        for &d in &dates {
+            let ngay_ta = NgayTa::from_date(d, 7.0);
+            assert_eq!((ngay_ta.day(), ngay_ta.month()), (17, 4));
+            assert!(!ngay_ta.is_leap_month());
+        }
+
+        // The range bounds are inclusive
+        let day = date(2024, Month::May, 24);
+        assert_eq!(anniversaries_in_range(17, 4, false, day, day, 7.0), [day]);
+        let next = day.next_day().unwrap();
+        assert!(anniversaries_in_range(17, 4, false, next, next, 7.0).is_empty());
+        assert!(anniversaries_in_range(17, 13, false, day, day, 7.0).is_empty());
+    }
+
+    #[test]
+    fn test_anniversaries_day_30_fallback() {
+        // Month 4 of 2024 only has 29 days
+        let dates = anniversaries_in_range(
+            30,
+            4,
+            false,
+            date(2024, Month::January, 1),
+            date(2024, Month::December, 31),
+            7.0,
+        );
+        assert_eq!(dates, [date(2024, Month::June, 5)]);
+        let ngay_ta = NgayTa::from_date(dates[0], 7.0);
+        assert_eq!((ngay_ta.day(), ngay_ta.month()), (29, 4));
+    }
+
+    #[test]
+    fn test_anniversaries_leap_month_fallback() {
+        let dates = anniversaries_in_range(
+            1,
+            6,
+            true,
+            date(2024, Month::January, 1),
+            date(2025, Month::December, 31),
+            7.0,
+        );
+        assert_eq!(dates.len(), 2);
+        // 2024 has no leap month 6
+        let first = NgayTa::from_date(dates[0], 7.0);
+        assert_eq!((first.month(), first.is_leap_month()), (6, false));
+        assert_eq!(dates[1], date(2025, Month::July, 25));
+    }
+
+    #[test]
+    fn test_solar_birthday_matches_lunar() {
+        // Born on 1/5/1990, the 19-year cycle brings it back twice
+        let birth = date(1990, Month::May, 24);
+        let matches = solar_birthday_matches_lunar(birth, 1990..=2050, 7.0);
+        let years: Vec<i32> = matches.iter().map(|&(year, _)| year).collect();
+        assert_eq!(years, [1990, 2009, 2028]);
+        for (year, birthday) in matches {
+            assert_eq!(birthday, date(year, Month::May, 24));
+            let ngay_ta = NgayTa::from_date(birthday, 7.0);
+            assert_eq!((ngay_ta.day(), ngay_ta.month()), (1, 5));
+        }
+
+        // Only leap years have a February 29
+        let birth = date(1996, Month::February, 29);
+        let matches = solar_birthday_matches_lunar(birth, 1997..=2080, 7.0);
+        assert_eq!(matches, [(2072, date(2072, Month::February, 29))]);
+    }
+}
@@ -0,0 +1,149 @@
+//! The [`NgayTaBuilder`] struct, setting the fields of a [`NgayTa`] one at a time.
+
+use crate::{LichTaError, NgayTa, Timezone};
+
+/// Builder of a [`NgayTa`], for code setting its fields one at a time.
+///
+/// The day and month default to 1, the month to a regular one and the timezone to
+/// [`Timezone::VIETNAM`]. The year has no default.
+///
+/// ```
+/// use lich_ta::{NgayTa, NgayTaBuilder};
+///
+/// let ngay_ta = NgayTaBuilder::new().day(15).month(6).year(2025).leap(true).build();
+/// assert_eq!(ngay_ta, Ok(NgayTa::new(15, 6, 2025, true)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NgayTaBuilder {
+    day: i32,
+    month: i32,
+    year: Option<i32>,
+    is_leap_month: bool,
+    timezone: f64,
+}
+
+impl Default for NgayTaBuilder {
+    fn default() -> Self {
+        Self {
+            day: 1,
+            month: 1,
+            year: None,
+            is_leap_month: false,
+            timezone: Timezone::VIETNAM.hours(),
+        }
+    }
+}
+
+impl NgayTaBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn day(mut self, day: i32) -> Self {
+        self.day = day;
+        self
+    }
+    pub fn month(mut self, month: i32) -> Self {
+        self.month = month;
+        self
+    }
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+    pub fn leap(mut self, is_leap_month: bool) -> Self {
+        self.is_leap_month = is_leap_month;
+        self
+    }
+    /// Set the timezone the date is checked in.
+    ///
+    /// Parameters:
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    pub fn timezone(mut self, timezone: f64) -> Self {
+        self.timezone = timezone;
+        self
+    }
+    /// Build the [`NgayTa`], fully validated like [`NgayTa::try_new`].
+    ///
+    /// Returns an error if the year was not set ([`LichTaError::DateOutOfRange`]), if the
+    /// month is not in `1..=12`, if the year has no such leap month, or if the day does not
+    /// exist in the month, e.g. day 30 of a 29-day month.
+    pub fn build(&self) -> Result<NgayTa, LichTaError> {
+        let year = self.year.ok_or(LichTaError::DateOutOfRange)?;
+        NgayTa::try_new(
+            self.day,
+            self.month,
+            year,
+            self.is_leap_month,
+            self.timezone,
+        )
+    }
+}
+
+impl NgayTa {
+    /// Start a [`NgayTaBuilder`], same as [`NgayTaBuilder::new`].
+    pub fn builder() -> NgayTaBuilder {
+        NgayTaBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let builder = NgayTa::builder().year(2025);
+        assert_eq!(builder.build(), Ok(NgayTa::new(1, 1, 2025, false)));
+        assert_eq!(
+            builder.day(30).month(6).build(),
+            Ok(NgayTa::new(30, 6, 2025, false))
+        );
+        // The setters can be called in any order and again
+        assert_eq!(
+            builder.month(3).leap(true).day(9).leap(false).build(),
+            Ok(NgayTa::new(9, 3, 2025, false))
+        );
+    }
+
+    #[test]
+    fn test_build_validates() {
+        assert_eq!(NgayTa::builder().build(), Err(LichTaError::DateOutOfRange));
+        let builder = NgayTa::builder().year(2025);
+        assert_eq!(
+            builder.month(13).build(),
+            Err(LichTaError::InvalidMonth(13))
+        );
+        assert_eq!(
+            builder.month(5).leap(true).build(),
+            Err(LichTaError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            })
+        );
+        // Month 6+ of 2025 has 29 days
+        assert_eq!(
+            builder.day(30).month(6).leap(true).build(),
+            Err(LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_timezone() {
+        // Month 9 of 2018 has 29 days in UTC+7, 30 in UTC+8
+        let builder = NgayTa::builder().day(30).month(9).year(2018);
+        assert_eq!(
+            builder.build(),
+            Err(LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+        assert_eq!(
+            builder.timezone(8.0).build(),
+            Ok(NgayTa::new(30, 9, 2018, false))
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Fixed-width byte encoding of a [`NgayTa`].
+
+use crate::NgayTa;
+
+/// Compute the CRC-8 (polynomial `0x07`) of `bytes`.
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |crc, &byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+impl NgayTa {
+    /// Encode the date in 6 bytes, without allocating.
+    ///
+    /// Layout:
+    /// - bytes 0-1: year, big-endian `i16`.
+    /// - byte 2: month, 1 to 12.
+    /// - byte 3: day, 1 to 30.
+    /// - byte 4: 1 for a leap month, else 0.
+    /// - byte 5: CRC-8 (polynomial `0x07`) of bytes 0-4.
+    ///
+    /// Panics if the year does not fit in an `i16` or the month or day in a `u8`.
+    pub fn to_bytes(&self) -> [u8; 6] {
+        let [year_high, year_low] = i16::try_from(self.year())
+            .expect("Year out of range for bytes")
+            .to_be_bytes();
+        let month = u8::try_from(self.month()).expect("Month out of range for bytes");
+        let day = u8::try_from(self.day()).expect("Day out of range for bytes");
+        let mut bytes = [
+            year_high,
+            year_low,
+            month,
+            day,
+            u8::from(self.is_leap_month()),
+            0,
+        ];
+        bytes[5] = crc8(&bytes[..5]);
+        bytes
+    }
+    /// Decode a date encoded by [`NgayTa::to_bytes`].
+    ///
+    /// Returns `None` if the checksum does not match or a field is out of its range. The date
+    /// is not checked against the calendar, use [`NgayTa::is_valid`] for that.
+    pub fn from_bytes(bytes: [u8; 6]) -> Option<Self> {
+        let [year_high, year_low, month, day, leap, checksum] = bytes;
+        if crc8(&bytes[..5]) != checksum
+            || !(1..=12).contains(&month)
+            || !(1..=30).contains(&day)
+            || leap > 1
+        {
+            return None;
+        }
+        let year = i16::from_be_bytes([year_high, year_low]);
+        Some(Self::new(day.into(), month.into(), year.into(), leap == 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for ngay_ta in [
+            NgayTa::new(17, 4, 2024, false),
+            NgayTa::new(30, 6, 2025, true),
+            NgayTa::new(1, 1, -1, false),
+        ] {
+            assert_eq!(NgayTa::from_bytes(ngay_ta.to_bytes()), Some(ngay_ta));
+        }
+        let bytes = NgayTa::new(17, 4, 2024, false).to_bytes();
+        assert_eq!(&bytes[..5], &[0x07, 0xe8, 4, 17, 0]);
+    }
+
+    #[test]
+    fn test_corrupt_bytes() {
+        let bytes = NgayTa::new(17, 4, 2024, false).to_bytes();
+        for i in 0..6 {
+            for bit in 0..8 {
+                let mut corrupt = bytes;
+                corrupt[i] ^= 1 << bit;
+                assert_eq!(NgayTa::from_bytes(corrupt), None);
+            }
+        }
+        assert_eq!(NgayTa::from_bytes([0; 6]), None);
+    }
+}
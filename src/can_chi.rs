@@ -0,0 +1,332 @@
+//! Heavenly stems ([`Can`]) and earthly branches ([`Chi`]) of the sexagenary cycle.
+
+use crate::{Date, NgayTa};
+
+/// Vietnamese names of the heavenly stems, in the order of [`Can::index`].
+///
+/// ```
+/// use lich_ta::{Can, CAN_NAMES};
+///
+/// assert_eq!(CAN_NAMES[Can::Binh.index()], Can::Binh.as_str());
+/// ```
+pub const CAN_NAMES: [&str; 10] = [
+    "Giáp", "Ất", "Bính", "Đinh", "Mậu", "Kỷ", "Canh", "Tân", "Nhâm", "Quý",
+];
+/// Vietnamese names of the earthly branches, in the order of [`Chi::index`].
+pub const CHI_NAMES: [&str; 12] = [
+    "Tý", "Sửu", "Dần", "Mão", "Thìn", "Tỵ", "Ngọ", "Mùi", "Thân", "Dậu", "Tuất", "Hợi",
+];
+
+/// Heavenly stem (Thiên can), cycling every 10 days, months and years.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Can {
+    Giap,
+    At,
+    Binh,
+    Dinh,
+    Mau,
+    Ky,
+    Canh,
+    Tan,
+    Nham,
+    Quy,
+}
+
+impl Can {
+    const ALL: [Can; 10] = [
+        Can::Giap,
+        Can::At,
+        Can::Binh,
+        Can::Dinh,
+        Can::Mau,
+        Can::Ky,
+        Can::Canh,
+        Can::Tan,
+        Can::Nham,
+        Can::Quy,
+    ];
+
+    /// Get the stem at `index` in the cycle starting from Giáp, wrapping around every 10.
+    pub fn from_index(index: i32) -> Self {
+        Self::ALL[index.rem_euclid(10) as usize]
+    }
+    /// Position in the cycle, from 0 (Giáp) to 9 (Quý).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Vietnamese name, e.g. "Giáp".
+    pub fn as_str(self) -> &'static str {
+        CAN_NAMES[self.index()]
+    }
+}
+
+/// Earthly branch (Địa chi), cycling every 12 days, months, years and two-hour periods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chi {
+    Ty,
+    Suu,
+    Dan,
+    Mao,
+    Thin,
+    Ti,
+    Ngo,
+    Mui,
+    Than,
+    Dau,
+    Tuat,
+    Hoi,
+}
+
+impl Chi {
+    const ALL: [Chi; 12] = [
+        Chi::Ty,
+        Chi::Suu,
+        Chi::Dan,
+        Chi::Mao,
+        Chi::Thin,
+        Chi::Ti,
+        Chi::Ngo,
+        Chi::Mui,
+        Chi::Than,
+        Chi::Dau,
+        Chi::Tuat,
+        Chi::Hoi,
+    ];
+
+    /// Get the branch at `index` in the cycle starting from Tý, wrapping around every 12.
+    pub fn from_index(index: i32) -> Self {
+        Self::ALL[index.rem_euclid(12) as usize]
+    }
+    /// Position in the cycle, from 0 (Tý) to 11 (Hợi).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Vietnamese name, e.g. "Tý".
+    pub fn as_str(self) -> &'static str {
+        CHI_NAMES[self.index()]
+    }
+}
+
+/// Get the Can Chi of a day.
+///
+/// The day cycle only depends on the Julian day number, not on the timezone: the Can is
+/// `(julian_day + 9) % 10` and the Chi is `(julian_day + 1) % 12`.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+///
+/// Return: (Can, Chi) of the day.
+pub fn day_can_chi(date: Date) -> (Can, Chi) {
+    let julian_day = date.to_julian_day();
+    (
+        Can::from_index(julian_day + 9),
+        Chi::from_index(julian_day + 1),
+    )
+}
+
+/// Get the Can of the Tý hour (23:00–01:00) starting a day, from which the hours count.
+///
+/// The stems of the hours repeat every 5 days: the Can is `(day_can % 5) * 2 % 10`, so Giáp and
+/// Kỷ days start with a Giáp Tý hour, Ất and Canh days with a Bính Tý hour and so on. Like
+/// [`day_can_chi`], it does not depend on the timezone.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+pub fn day_hour_base_can(date: Date) -> Can {
+    let (day_can, _) = day_can_chi(date);
+    Can::from_index((day_can.index() % 5 * 2 % 10) as i32)
+}
+
+/// Get the Can Chi of an hour.
+///
+/// Each Chi covers two hours, Tý from 23:00 to 01:00. The Tý hour beginning at 23:00 is the
+/// first hour of the next day, so hour 23 takes its Can from the next day.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `hour`: Local hour, from 0 to 23.
+///
+/// Return: (Can, Chi) of the hour.
+///
+/// Panics if `hour` is not in `0..24`.
+pub fn hour_can_chi(date: Date, hour: u8) -> (Can, Chi) {
+    assert!(hour < 24, "Hour must be in 0..24");
+    let chi_index = (i32::from(hour) + 1) / 2 % 12;
+    let day = if hour == 23 {
+        date.next_day().expect("Invalid date for hour")
+    } else {
+        date
+    };
+    let base = day_hour_base_can(day);
+    (
+        Can::from_index(base.index() as i32 + chi_index),
+        Chi::from_index(chi_index),
+    )
+}
+
+/// Get the Can Chi of a lunar year.
+///
+/// Years use the astronomical numbering of [`Date`], year 0 is 1 BCE, and the cycle keeps
+/// going backward through it: year 4 is Giáp Tý, so year -56 is Giáp Tý too.
+///
+/// Parameters:
+/// - `year`: Lunar year.
+///
+/// Return: (Can, Chi) of the year.
+pub fn year_can_chi(year: i32) -> (Can, Chi) {
+    (Can::from_index(year + 6), Chi::from_index(year + 8))
+}
+
+/// Get the Can Chi of a lunar month.
+///
+/// The Chi is fixed by the month number, Dần for month 1 up to Sửu for month 12. The Can
+/// continues from year to year: month 1 of a Giáp or Kỷ year is Bính Dần, so the Can is
+/// `(year_can * 2 + month + 1) % 10`. A leap month has the Can Chi of the month it repeats.
+///
+/// Parameters:
+/// - `month`: Lunar month from 1 to 12.
+/// - `year`: Lunar year.
+///
+/// Return: (Can, Chi) of the month.
+pub fn month_can_chi(month: i32, year: i32) -> (Can, Chi) {
+    let (year_can, _) = year_can_chi(year);
+    (
+        Can::from_index(year_can.index() as i32 * 2 + month + 1),
+        Chi::from_index(month + 1),
+    )
+}
+
+impl NgayTa {
+    /// Get the month number, its Can Chi and the leap flag together, for a month heading such
+    /// as "tháng 6 nhuận, Quý Mùi".
+    ///
+    /// ```
+    /// use lich_ta::{Can, Chi, NgayTa};
+    ///
+    /// let ngay_ta = NgayTa::new(1, 6, 2025, true);
+    /// assert_eq!(ngay_ta.month_full(), (6, Can::Quy, Chi::Mui, true));
+    /// ```
+    pub fn month_full(&self) -> (i32, Can, Chi, bool) {
+        let (can, chi) = month_can_chi(self.month(), self.year());
+        (self.month(), can, chi, self.is_leap_month())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_index() {
+        assert_eq!(Can::from_index(0), Can::Giap);
+        assert_eq!(Can::from_index(19), Can::Quy);
+        assert_eq!(Can::from_index(-1), Can::Quy);
+        assert_eq!(Chi::from_index(12), Chi::Ty);
+        assert_eq!(Chi::from_index(-1), Chi::Hoi);
+        assert_eq!(Can::Mau.as_str(), "Mậu");
+        assert_eq!(Chi::Thin.as_str(), "Thìn");
+    }
+
+    #[test]
+    fn test_year_can_chi() {
+        assert_eq!(year_can_chi(2024), (Can::Giap, Chi::Thin));
+        assert_eq!(year_can_chi(1984), (Can::Giap, Chi::Ty));
+        assert_eq!(year_can_chi(4), (Can::Giap, Chi::Ty));
+        assert_eq!(year_can_chi(0), (Can::Canh, Chi::Than));
+        assert_eq!(year_can_chi(-1), (Can::Ky, Chi::Mui));
+        assert_eq!(year_can_chi(-56), (Can::Giap, Chi::Ty));
+    }
+
+    #[test]
+    fn test_day_can_chi() {
+        // Tết Giáp Thìn was a Giáp Thìn day
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        assert_eq!(day_can_chi(date), (Can::Giap, Chi::Thin));
+        let date = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+        assert_eq!(day_can_chi(date), (Can::Mau, Chi::Ngo));
+        // The cycle keeps going before the Julian day 0
+        let date = Date::from_julian_day(-1).unwrap();
+        let previous = Date::from_julian_day(-61).unwrap();
+        assert_eq!(day_can_chi(date), day_can_chi(previous));
+        assert_eq!(day_can_chi(date), (Can::Nham, Chi::Ty));
+    }
+
+    #[test]
+    fn test_day_hour_base_can() {
+        // 2024-02-10 is a Giáp Thìn day, the following days are Ất, Bính, Đinh, Mậu, Kỷ...
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        let expected = [
+            Can::Giap,
+            Can::Binh,
+            Can::Mau,
+            Can::Canh,
+            Can::Nham,
+            Can::Giap,
+        ];
+        for (i, base) in expected.into_iter().enumerate() {
+            let day = date + time::Duration::days(i as i64);
+            assert_eq!(day_hour_base_can(day), base);
+            assert_eq!(hour_can_chi(day, 0), (base, Chi::Ty));
+        }
+    }
+
+    #[test]
+    fn test_month_can_chi() {
+        // Giáp Thìn starts with a Bính Dần month, Ất Tỵ with a Mậu Dần one
+        assert_eq!(month_can_chi(1, 2024), (Can::Binh, Chi::Dan));
+        assert_eq!(month_can_chi(12, 2024), (Can::Dinh, Chi::Suu));
+        assert_eq!(month_can_chi(1, 2025), (Can::Mau, Chi::Dan));
+        // The Can continues across the years
+        for year in [-60, 0, 1900, 2024, 2025] {
+            let (last_can, _) = month_can_chi(12, year);
+            let (first_can, _) = month_can_chi(1, year + 1);
+            assert_eq!(first_can.index(), (last_can.index() + 1) % 10, "{year}");
+        }
+
+        assert_eq!(
+            NgayTa::new(1, 6, 2025, true).month_full(),
+            (6, Can::Quy, Chi::Mui, true)
+        );
+        assert_eq!(
+            NgayTa::new(15, 6, 2025, false).month_full(),
+            (6, Can::Quy, Chi::Mui, false)
+        );
+        assert_eq!(
+            NgayTa::new(17, 4, 2024, false).month_full(),
+            (4, Can::Ky, Chi::Ti, false)
+        );
+    }
+
+    #[test]
+    fn test_hour_can_chi() {
+        // Giáp day: Giáp Tý, Ất Sửu... Ất Hợi, then the Bính Tý hour of the Ất day
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        assert_eq!(hour_can_chi(date, 1), (Can::At, Chi::Suu));
+        assert_eq!(hour_can_chi(date, 12), (Can::Canh, Chi::Ngo));
+        assert_eq!(hour_can_chi(date, 22), (Can::At, Chi::Hoi));
+        assert_eq!(hour_can_chi(date, 23), (Can::Binh, Chi::Ty));
+        assert_eq!(
+            hour_can_chi(date, 23),
+            hour_can_chi(date.next_day().unwrap(), 0)
+        );
+    }
+
+    #[test]
+    fn test_name_tables() {
+        assert_eq!((CAN_NAMES.len(), CHI_NAMES.len()), (10, 12));
+        for names in [&CAN_NAMES[..], &CHI_NAMES[..]] {
+            for (i, name) in names.iter().enumerate() {
+                assert!(!name.is_empty());
+                assert!(!names[..i].contains(name), "{name} is repeated");
+            }
+        }
+        for (i, can) in Can::ALL.iter().enumerate() {
+            assert_eq!(can.index(), i);
+        }
+        for (i, chi) in Chi::ALL.iter().enumerate() {
+            assert_eq!(chi.index(), i);
+        }
+        assert_eq!((Can::Giap.as_str(), Can::Quy.as_str()), ("Giáp", "Quý"));
+        assert_eq!((Chi::Ty.as_str(), Chi::Hoi.as_str()), ("Tý", "Hợi"));
+    }
+}
@@ -0,0 +1,407 @@
+//! The [`Converter`] struct and its associated `impl`s.
+
+use crate::util::{
+    convert_date_to_lichta_with, get_leap_month_offset, get_lunar_month_11_with_lookback,
+    get_month_start, gregorian_of_lunar_day_with, Conversion, YearValues,
+};
+use crate::{Date, LichTaDetails, LichTaError, NgayTa};
+
+/// Number of years remembered by each cache of a [`Converter`].
+const CACHE_SIZE: usize = 4;
+
+/// Small map from `i32` to `i32`, replacing its entries in round-robin order.
+#[derive(Clone, Debug)]
+struct RoundRobinCache {
+    entries: [Option<(i32, i32)>; CACHE_SIZE],
+    next_slot: usize,
+}
+
+impl RoundRobinCache {
+    fn new() -> Self {
+        Self {
+            entries: [None; CACHE_SIZE],
+            next_slot: 0,
+        }
+    }
+
+    fn get_or_insert_with(&mut self, key: i32, f: impl FnOnce() -> i32) -> i32 {
+        let cached = self.entries.iter().flatten().find(|(k, _)| *k == key);
+        if let Some(&(_, value)) = cached {
+            return value;
+        }
+        let value = f();
+        self.entries[self.next_slot] = Some((key, value));
+        self.next_slot = (self.next_slot + 1) % CACHE_SIZE;
+        value
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+}
+
+/// Converter between Gregorian dates and the LichTa calendar for one timezone.
+///
+/// Most of the cost of a conversion is finding the month 11 of the surrounding years and,
+/// in a 13-month year, its leap month. The converter remembers them for the last few years it
+/// has seen, so converting many dates of the same years computes them only once. Results are
+/// identical to [`convert_date_to_lichta`].
+///
+/// [`convert_date_to_lichta`]: crate::convert_date_to_lichta
+#[derive(Clone, Debug)]
+pub struct Converter {
+    timezone: f64,
+    /// Days before December 31 where the search for the month 11 starts.
+    month_11_lookback: u8,
+    /// Julian day of the month 11 by Gregorian year.
+    lunar_months_11: RoundRobinCache,
+    /// Leap month offset by Julian day of the first month 11.
+    leap_month_offsets: RoundRobinCache,
+}
+
+impl Converter {
+    /// Longest lookback [`Converter::with_month_11_lookback`] accepts, in days.
+    pub const MAX_MONTH_11_LOOKBACK: u8 = 7;
+
+    pub fn new(timezone: f64) -> Self {
+        Self {
+            timezone,
+            month_11_lookback: 0,
+            lunar_months_11: RoundRobinCache::new(),
+            leap_month_offsets: RoundRobinCache::new(),
+        }
+    }
+    /// Start the search for the month 11 of each year `days` before December 31.
+    ///
+    /// The month 11 is found from the last new moon before December 31 by default. It starts
+    /// by December 22 at the latest, and a lookback up to 7 days finds the same months; a
+    /// longer one can miss a month 11 starting late in December and pick the month 10 instead,
+    /// so `days` is clamped to [`Converter::MAX_MONTH_11_LOOKBACK`].
+    pub fn with_month_11_lookback(mut self, days: u8) -> Self {
+        self.month_11_lookback = days.min(Self::MAX_MONTH_11_LOOKBACK);
+        self.lunar_months_11 = RoundRobinCache::new();
+        self.leap_month_offsets = RoundRobinCache::new();
+        self
+    }
+    pub fn timezone(&self) -> f64 {
+        self.timezone
+    }
+    pub fn month_11_lookback(&self) -> u8 {
+        self.month_11_lookback
+    }
+}
+
+impl Converter {
+    /// Convert a Gregorian date to the LichTa calendar.
+    pub fn convert(&mut self, date: Date) -> NgayTa {
+        let (day, month, year, is_leap_month) = self.convert_to_tuple(date);
+        NgayTa::new(day, month, year, is_leap_month == 1)
+    }
+
+    /// Same as [`Converter::convert`], in the tuple form of [`crate::convert_date_to_lichta`].
+    pub fn convert_to_tuple(&mut self, date: Date) -> (i32, i32, i32, i32) {
+        self.convert_with_details(date).to_tuple()
+    }
+
+    /// Same as [`Converter::convert`], along with the intermediate values of the conversion.
+    pub fn convert_details(&mut self, date: Date) -> LichTaDetails {
+        LichTaDetails::from_conversion(self.convert_with_details(date), self.timezone)
+    }
+
+    /// Convert a LichTa date back to the Gregorian calendar, same as [`NgayTa::to_date`].
+    pub fn to_date(&mut self, ngay_ta: NgayTa) -> Result<Date, LichTaError> {
+        gregorian_of_lunar_day_with(
+            ngay_ta.year(),
+            ngay_ta.month(),
+            ngay_ta.is_leap_month(),
+            ngay_ta.day(),
+            self.timezone,
+            self,
+        )
+    }
+
+    fn convert_with_details(&mut self, date: Date) -> Conversion {
+        convert_date_to_lichta_with(date, self.timezone, self)
+    }
+}
+
+impl YearValues for Converter {
+    fn lunar_month_11(&mut self, year: i32) -> i32 {
+        let (timezone, lookback) = (self.timezone, self.month_11_lookback);
+        self.lunar_months_11.get_or_insert_with(year, || {
+            get_lunar_month_11_with_lookback(year, lookback, timezone) as i32
+        })
+    }
+    fn leap_month_offset(&mut self, first_month_11: i32) -> i32 {
+        let timezone = self.timezone;
+        self.leap_month_offsets
+            .get_or_insert_with(first_month_11, || {
+                get_leap_month_offset(first_month_11, timezone)
+            })
+    }
+}
+
+/// Lunar month of the last date converted by a [`SequentialConverter`].
+#[derive(Clone, Copy, Debug)]
+struct CurrentMonth {
+    /// Julian day of the last date converted.
+    julian_day: i32,
+    /// Julian day of the first day of the month.
+    start: i32,
+    /// Julian day of the first day of the next month.
+    next_start: i32,
+    /// First day of the month.
+    first_day: NgayTa,
+}
+
+/// Converter for dates given in increasing order, e.g. every day of a range.
+///
+/// It remembers the lunar month of the last date, so a date in the same month is numbered
+/// from the month's first day without any astronomical computation. Only the first date of
+/// each month goes through a [`Converter`]. Results are identical to
+/// [`convert_date_to_lichta`](crate::convert_date_to_lichta).
+///
+/// ```
+/// use lich_ta::{Date, NgayTa, SequentialConverter};
+///
+/// let mut converter = SequentialConverter::new(7.0);
+/// let mut date = Date::from_calendar_date(2025, time::Month::January, 29).unwrap();
+/// assert_eq!(converter.next(date), NgayTa::new(1, 1, 2025, false));
+/// date = date.next_day().unwrap();
+/// assert_eq!(converter.next(date), NgayTa::new(2, 1, 2025, false));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequentialConverter {
+    converter: Converter,
+    current: Option<CurrentMonth>,
+}
+
+impl SequentialConverter {
+    pub fn new(timezone: f64) -> Self {
+        Self {
+            converter: Converter::new(timezone),
+            current: None,
+        }
+    }
+    pub fn timezone(&self) -> f64 {
+        self.converter.timezone()
+    }
+    /// Convert the next date of the sequence to the LichTa calendar.
+    ///
+    /// Dates may repeat or skip days, only going back is not allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date` is before the previous date, or outside
+    /// [`supported_date_range`](crate::supported_date_range).
+    pub fn next(&mut self, date: Date) -> NgayTa {
+        let julian_day = date.to_julian_day();
+        if let Some(current) = &mut self.current {
+            assert!(
+                julian_day >= current.julian_day,
+                "Dates must be increasing for SequentialConverter"
+            );
+            if julian_day < current.next_start {
+                current.julian_day = julian_day;
+                let first_day = current.first_day;
+                return NgayTa::new(
+                    julian_day - current.start + 1,
+                    first_day.month(),
+                    first_day.year(),
+                    first_day.is_leap_month(),
+                );
+            }
+        }
+        let conversion = self.converter.convert_with_details(date);
+        let (day, month, year, is_leap_month) = conversion.to_tuple();
+        // A month has at most 30 days, 30 days after its start is always in the next month
+        let next_start = get_month_start(conversion.month_start + 30, self.timezone());
+        self.current = Some(CurrentMonth {
+            julian_day,
+            start: conversion.month_start,
+            next_start,
+            first_day: NgayTa::new(1, month, year, is_leap_month == 1),
+        });
+        NgayTa::new(day, month, year, is_leap_month == 1)
+    }
+}
+
+/// Convert LichTa dates back to the Gregorian calendar, sharing one [`Converter`].
+///
+/// Each entry gets its own result, an impossible date does not stop the others.
+#[cfg(feature = "alloc")]
+pub fn convert_lunar_dates(
+    dates: &[NgayTa],
+    timezone: f64,
+) -> alloc::vec::Vec<Result<Date, LichTaError>> {
+    let mut converter = Converter::new(timezone);
+    dates
+        .iter()
+        .map(|&ngay_ta| converter.to_date(ngay_ta))
+        .collect()
+}
+
+/// Convert a Gregorian date as seen in each of `timezones`, e.g. Vietnam next to the local
+/// timezone of a user abroad.
+///
+/// New moons close to midnight can fall on different days in different timezones, so the
+/// lunar dates may differ by a day, a month or, around Tết, a year. Timezones repeated in
+/// the list are converted once.
+#[cfg(feature = "alloc")]
+pub fn convert_multi_tz(date: Date, timezones: &[f64]) -> alloc::vec::Vec<NgayTa> {
+    let mut results: alloc::vec::Vec<NgayTa> = alloc::vec::Vec::with_capacity(timezones.len());
+    for (i, &timezone) in timezones.iter().enumerate() {
+        let previous = timezones[..i]
+            .iter()
+            .position(|&other| other.to_bits() == timezone.to_bits());
+        let ngay_ta = match previous {
+            Some(j) => results[j],
+            None => NgayTa::from_date(date, timezone),
+        };
+        results.push(ngay_ta);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_date_to_lichta;
+
+    #[test]
+    fn test_convert_matches_uncached() {
+        let mut converter = Converter::new(7.0);
+        let mut date = Date::from_calendar_date(2025, time::Month::January, 1).unwrap();
+        while date.year() < 2027 {
+            assert_eq!(
+                converter.convert_to_tuple(date),
+                convert_date_to_lichta(date, 7.0)
+            );
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sequential_converter() {
+        let mut converter = SequentialConverter::new(7.0);
+        let mut date = Date::from_calendar_date(2023, time::Month::December, 1).unwrap();
+        while date.year() < 2027 {
+            let ngay_ta = converter.next(date);
+            assert_eq!(ngay_ta, convert_date_to_lichta(date, 7.0), "{date}");
+            // Repeating a date gives the same result
+            assert_eq!(converter.next(date), ngay_ta);
+            date = date.next_day().unwrap();
+        }
+        // Skipping days and whole months
+        let mut converter = SequentialConverter::new(7.0);
+        let mut date = Date::from_calendar_date(2025, time::Month::January, 1).unwrap();
+        for step in [1, 28, 29, 30, 45, 365].into_iter().cycle().take(30) {
+            assert_eq!(
+                converter.next(date),
+                convert_date_to_lichta(date, 7.0),
+                "{date}"
+            );
+            date += time::Duration::days(step);
+        }
+        assert_eq!(converter.timezone(), 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dates must be increasing")]
+    fn test_sequential_converter_going_back() {
+        let mut converter = SequentialConverter::new(7.0);
+        let date = Date::from_calendar_date(2025, time::Month::January, 2).unwrap();
+        converter.next(date);
+        converter.next(date.previous_day().unwrap());
+    }
+
+    #[test]
+    fn test_leap_month_offset_is_cached() {
+        let mut converter = Converter::new(7.0);
+        let date = Date::from_calendar_date(2025, time::Month::August, 1).unwrap();
+        let ngay_ta = converter.convert(date);
+        assert_eq!((ngay_ta.month(), ngay_ta.is_leap_month()), (6, true));
+        assert_eq!(converter.leap_month_offsets.len(), 1);
+        assert_eq!(converter.lunar_months_11.len(), 2);
+
+        converter.convert(date.next_day().unwrap());
+        assert_eq!(converter.leap_month_offsets.len(), 1);
+        assert_eq!(converter.lunar_months_11.len(), 2);
+    }
+
+    #[test]
+    fn test_month_11_lookback() {
+        let mut converter = Converter::new(7.0).with_month_11_lookback(7);
+        assert_eq!(converter.month_11_lookback(), 7);
+        // Longer lookbacks could pick the month 10 of a year
+        let clamped = Converter::new(7.0).with_month_11_lookback(8);
+        assert_eq!(
+            clamped.month_11_lookback(),
+            Converter::MAX_MONTH_11_LOOKBACK
+        );
+        let clamped = Converter::new(7.0).with_month_11_lookback(u8::MAX);
+        assert_eq!(
+            clamped.month_11_lookback(),
+            Converter::MAX_MONTH_11_LOOKBACK
+        );
+        for year in [1900, 2024, 2025, 2034] {
+            let mut date = Date::from_calendar_date(year, time::Month::January, 1).unwrap();
+            while date.month() == time::Month::January {
+                assert_eq!(
+                    converter.convert_to_tuple(date),
+                    convert_date_to_lichta(date, 7.0)
+                );
+                date = date.next_day().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_date_matches_uncached() {
+        let mut converter = Converter::new(7.0);
+        let mut date = Date::from_calendar_date(2025, time::Month::January, 1).unwrap();
+        while date.year() < 2027 {
+            let ngay_ta = NgayTa::from_date(date, 7.0);
+            assert_eq!(converter.to_date(ngay_ta), ngay_ta.to_date(7.0));
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_convert_lunar_dates() {
+        let dates = [
+            NgayTa::new(17, 4, 2024, false),
+            NgayTa::new(30, 4, 2024, false),
+            NgayTa::new(1, 6, 2025, true),
+        ];
+        let results = convert_lunar_dates(&dates, 7.0);
+        assert_eq!(
+            results,
+            [
+                Ok(Date::from_calendar_date(2024, time::Month::May, 24).unwrap()),
+                Err(LichTaError::DayOutOfRange {
+                    day: 30,
+                    month_length: 29
+                }),
+                Ok(Date::from_calendar_date(2025, time::Month::July, 25).unwrap()),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_convert_multi_tz() {
+        // The new moon of 2023-03-21 17:23 UTC starts the month a day later in UTC+9
+        let date = Date::from_calendar_date(2023, time::Month::March, 22).unwrap();
+        let results = convert_multi_tz(date, &[7.0, 9.0, 7.0, 0.0]);
+        assert_eq!(results.len(), 4);
+        for (result, timezone) in results.iter().zip([7.0, 9.0, 7.0, 0.0]) {
+            assert_eq!(*result, NgayTa::from_date(date, timezone));
+        }
+        assert_eq!(results[0], results[2]);
+        assert_ne!(results[1], results[3]);
+        assert!(convert_multi_tz(date, &[]).is_empty());
+    }
+}
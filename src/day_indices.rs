@@ -0,0 +1,114 @@
+//! The [`DayIndices`] struct, the positions of a day in the week and the Can Chi cycles.
+
+use time::Weekday;
+
+use crate::{day_can_chi, Date, LichTaDetails, LichTaError, NgayTa, SexagenaryDay};
+
+/// Positions of a day in each cycle the almanac uses, all derived from its Julian day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DayIndices {
+    julian_day: i32,
+    weekday: Weekday,
+    can_index: usize,
+    chi_index: usize,
+    sexagenary_index: usize,
+}
+
+impl DayIndices {
+    pub fn from_date(date: Date) -> Self {
+        let (can, chi) = day_can_chi(date);
+        let julian_day = date.to_julian_day();
+        Self {
+            julian_day,
+            weekday: date.weekday(),
+            can_index: can.index(),
+            chi_index: chi.index(),
+            sexagenary_index: SexagenaryDay::from_date(date).index(),
+        }
+    }
+}
+
+impl DayIndices {
+    /// Julian day number, from which the other indices are computed.
+    pub fn julian_day(&self) -> i32 {
+        self.julian_day
+    }
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+    /// Index of the day Can, from 0 (Giáp) to 9 (Quý).
+    pub fn can_index(&self) -> usize {
+        self.can_index
+    }
+    /// Index of the day Chi, from 0 (Tý) to 11 (Hợi).
+    pub fn chi_index(&self) -> usize {
+        self.chi_index
+    }
+    /// Index in the 60-day cycle, from 0 (Giáp Tý) to 59 (Quý Hợi).
+    pub fn sexagenary_index(&self) -> usize {
+        self.sexagenary_index
+    }
+}
+
+impl NgayTa {
+    /// Get the position of the day in the 60-day cycle, from 0 (Giáp Tý) to 59 (Quý Hợi).
+    ///
+    /// Parameters:
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    ///
+    /// Returns the same errors as [`NgayTa::to_date`].
+    pub fn days_since_giap_ty(&self, timezone: f64) -> Result<u8, LichTaError> {
+        let date = self.to_date(timezone)?;
+        Ok(DayIndices::from_date(date).sexagenary_index() as u8)
+    }
+}
+
+impl LichTaDetails {
+    /// Positions of the converted day in the week and the Can Chi cycles.
+    pub fn day_indices(&self) -> DayIndices {
+        let date = self.month_start() + time::Duration::days((self.ngay_ta().day() - 1).into());
+        DayIndices::from_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_indices() {
+        // Tết Giáp Thìn, a Saturday
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        let indices = DayIndices::from_date(date);
+        assert_eq!(indices.julian_day(), 2_460_351);
+        assert_eq!(indices.weekday(), Weekday::Saturday);
+        assert_eq!((indices.can_index(), indices.chi_index()), (0, 4));
+        assert_eq!(indices.sexagenary_index(), 40);
+        for days in 0..60 {
+            let indices = DayIndices::from_date(date + time::Duration::days(days));
+            assert_eq!(indices.sexagenary_index() % 10, indices.can_index());
+            assert_eq!(indices.sexagenary_index() % 12, indices.chi_index());
+        }
+    }
+
+    #[test]
+    fn test_days_since_giap_ty() {
+        // Giáp Thìn is the 41st day of the cycle
+        let tet = NgayTa::new(1, 1, 2024, false);
+        assert_eq!(tet.days_since_giap_ty(7.0), Ok(40));
+        assert_eq!(
+            NgayTa::new(21, 1, 2024, false).days_since_giap_ty(7.0),
+            Ok(0)
+        );
+        assert!(NgayTa::new(30, 4, 2024, false)
+            .days_since_giap_ty(7.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_details_day_indices() {
+        let date = Date::from_calendar_date(2025, time::Month::August, 10).unwrap();
+        let details = LichTaDetails::from_date(date, 7.0);
+        assert_eq!(details.day_indices(), DayIndices::from_date(date));
+    }
+}
@@ -0,0 +1,141 @@
+//! The [`LichTaDetails`] struct and its associated `impl`s.
+
+use crate::util::{convert_date_to_lichta_with, is_near_month_boundary, Conversion, Uncached};
+use crate::{Date, NgayTa};
+
+/// Result of a conversion along with the intermediate values of the algorithm.
+#[derive(Clone, Copy, Debug)]
+pub struct LichTaDetails {
+    ngay_ta: NgayTa,
+    month_start: Date,
+    month_difference: i32,
+    boundary: bool,
+}
+
+impl LichTaDetails {
+    pub fn from_date(date: Date, timezone: f64) -> Self {
+        let conversion = convert_date_to_lichta_with(date, timezone, &mut Uncached(timezone));
+        Self::from_conversion(conversion, timezone)
+    }
+    pub(crate) fn from_conversion(conversion: Conversion, timezone: f64) -> Self {
+        let month_start =
+            Date::from_julian_day(conversion.month_start).expect("Invalid date for month start");
+        let julian_day = conversion.month_start + conversion.lunar_day - 1;
+        Self {
+            ngay_ta: NgayTa::new(
+                conversion.lunar_day,
+                conversion.lunar_month,
+                conversion.lunar_year,
+                conversion.lunar_leap == 1,
+            ),
+            month_start,
+            month_difference: conversion.month_difference,
+            boundary: is_near_month_boundary(julian_day, timezone),
+        }
+    }
+}
+
+impl LichTaDetails {
+    pub fn ngay_ta(&self) -> NgayTa {
+        self.ngay_ta
+    }
+    /// Gregorian date of the first day of the lunar month.
+    pub fn month_start(&self) -> Date {
+        self.month_start
+    }
+    /// Number of new moons from the month 11 preceding the date to the start of its month.
+    ///
+    /// This is the raw month numbering of the algorithm, it is 0 for the month 11 itself.
+    /// The displayed month is `month_difference + 11`, minus one from the leap month onward
+    /// in a 13-month year, brought back into `1..=12`.
+    pub fn month_difference(&self) -> i32 {
+        self.month_difference
+    }
+    /// Whether the date is the day before or after a midnight within 30 minutes of a new moon.
+    ///
+    /// The new moon is only known to a few minutes, so another model, e.g. the `high-accuracy`
+    /// feature, may start the month on the other side of that midnight, moving this date into
+    /// the neighbouring month.
+    pub fn is_near_month_boundary(&self) -> bool {
+        self.boundary
+    }
+}
+
+/// Convert a Gregorian date to the LichTa calendar, flagging a date next to a month boundary
+/// that a more accurate model may move, like [`LichTaDetails::is_near_month_boundary`].
+///
+/// ```
+/// use lich_ta::{convert_with_confidence, Date};
+///
+/// // The new moon of 1944-06-20 is minutes from midnight in UTC+7
+/// let date = Date::from_calendar_date(1944, time::Month::June, 20).unwrap();
+/// assert!(convert_with_confidence(date, 7.0).1);
+/// let date = Date::from_calendar_date(2025, time::Month::January, 29).unwrap();
+/// assert!(!convert_with_confidence(date, 7.0).1);
+/// ```
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The lunar date, and whether it is near a month boundary.
+pub fn convert_with_confidence(date: Date, timezone: f64) -> (NgayTa, bool) {
+    let details = LichTaDetails::from_date(date, timezone);
+    (details.ngay_ta(), details.is_near_month_boundary())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_difference() {
+        // Month 11 of 2024 begins on 2024-12-01
+        let date = Date::from_calendar_date(2024, time::Month::December, 1).unwrap();
+        let details = LichTaDetails::from_date(date, 7.0);
+        assert_eq!(details.month_difference(), 12);
+        assert_eq!(details.month_start(), date);
+
+        // 2025 has a leap month 6, month 7 is one month further than its number suggests
+        let date = Date::from_calendar_date(2025, time::Month::September, 1).unwrap();
+        let details = LichTaDetails::from_date(date, 7.0);
+        assert_eq!(
+            (details.ngay_ta().month(), details.month_difference()),
+            (7, 9)
+        );
+        assert_eq!(
+            details.month_start(),
+            Date::from_calendar_date(2025, time::Month::August, 23).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_near_month_boundary() {
+        // Months the `high-accuracy` feature starts a day earlier in UTC+7
+        for (year, month, day) in [(1944, time::Month::June, 20), (1967, time::Month::July, 7)] {
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            for date in [date, date.next_day().unwrap()] {
+                assert!(
+                    LichTaDetails::from_date(date, 7.0).is_near_month_boundary(),
+                    "{date}"
+                );
+                assert!(convert_with_confidence(date, 7.0).1, "{date}");
+                let details = crate::Converter::new(7.0).convert_details(date);
+                assert!(details.is_near_month_boundary(), "{date}");
+            }
+            let next_days = [
+                date + time::Duration::days(2),
+                date - time::Duration::days(1),
+            ];
+            for date in next_days {
+                assert!(!convert_with_confidence(date, 7.0).1, "{date}");
+            }
+        }
+        // The new moon of 2025-01-29 is at 19:36 in UTC+7
+        let date = Date::from_calendar_date(2025, time::Month::January, 29).unwrap();
+        assert_eq!(
+            convert_with_confidence(date, 7.0),
+            (NgayTa::new(1, 1, 2025, false), false)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Auspicious travel directions (hướng xuất hành) of a day.
+
+use crate::can_chi::{day_can_chi, Can};
+use crate::Date;
+
+/// One of the 8 compass points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Bac,
+    DongBac,
+    Dong,
+    DongNam,
+    Nam,
+    TayNam,
+    Tay,
+    TayBac,
+}
+
+impl Direction {
+    /// Vietnamese name, e.g. "Đông Bắc".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Bac => "Bắc",
+            Direction::DongBac => "Đông Bắc",
+            Direction::Dong => "Đông",
+            Direction::DongNam => "Đông Nam",
+            Direction::Nam => "Nam",
+            Direction::TayNam => "Tây Nam",
+            Direction::Tay => "Tây",
+            Direction::TayBac => "Tây Bắc",
+        }
+    }
+}
+
+/// Directions to head to when setting out on a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TravelDirections {
+    hy_than: Direction,
+    tai_than: Direction,
+}
+
+impl TravelDirections {
+    /// Direction of Hỷ Thần, the god of joy.
+    pub fn hy_than(&self) -> Direction {
+        self.hy_than
+    }
+    /// Direction of Tài Thần, the god of wealth.
+    pub fn tai_than(&self) -> Direction {
+        self.tai_than
+    }
+}
+
+/// Get the auspicious travel directions of a day, from the standard table keyed by its Can.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+pub fn travel_directions(date: Date) -> TravelDirections {
+    let (can, _) = day_can_chi(date);
+    let (hy_than, tai_than) = match can {
+        Can::Giap => (Direction::DongBac, Direction::DongNam),
+        Can::At => (Direction::TayBac, Direction::DongNam),
+        Can::Binh => (Direction::TayNam, Direction::Dong),
+        Can::Dinh => (Direction::Nam, Direction::Dong),
+        Can::Mau => (Direction::DongNam, Direction::Bac),
+        Can::Ky => (Direction::DongBac, Direction::Nam),
+        Can::Canh => (Direction::TayBac, Direction::TayNam),
+        Can::Tan => (Direction::TayNam, Direction::TayNam),
+        Can::Nham => (Direction::Nam, Direction::Tay),
+        Can::Quy => (Direction::DongNam, Direction::TayBac),
+    };
+    TravelDirections { hy_than, tai_than }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_travel_directions() {
+        // Giáp Thìn day
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        let directions = travel_directions(date);
+        assert_eq!(directions.hy_than(), Direction::DongBac);
+        assert_eq!(directions.tai_than(), Direction::DongNam);
+
+        // Mậu Ngọ day
+        let date = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+        let directions = travel_directions(date);
+        assert_eq!(directions.hy_than().as_str(), "Đông Nam");
+        assert_eq!(directions.tai_than().as_str(), "Bắc");
+    }
+}
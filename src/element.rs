@@ -0,0 +1,133 @@
+//! The five elements ([`Element`]) and the relation between two stems.
+
+use crate::Can;
+
+/// Element (hành) of the five phases (ngũ hành).
+///
+/// Variants are in the order of the generating cycle: each element generates the next one and
+/// overcomes the one after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Element {
+    /// Wood.
+    Moc,
+    /// Fire.
+    Hoa,
+    /// Earth.
+    Tho,
+    /// Metal.
+    Kim,
+    /// Water.
+    Thuy,
+}
+
+impl Element {
+    const ALL: [Element; 5] = [
+        Element::Moc,
+        Element::Hoa,
+        Element::Tho,
+        Element::Kim,
+        Element::Thuy,
+    ];
+
+    /// Vietnamese name, e.g. "Mộc".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Element::Moc => "Mộc",
+            Element::Hoa => "Hỏa",
+            Element::Tho => "Thổ",
+            Element::Kim => "Kim",
+            Element::Thuy => "Thủy",
+        }
+    }
+    /// Get the element this one generates (tương sinh), e.g. Mộc generates Hỏa.
+    pub fn generates(self) -> Self {
+        Self::ALL[(self as usize + 1) % 5]
+    }
+    /// Get the element this one overcomes (tương khắc), e.g. Mộc overcomes Thổ.
+    pub fn overcomes(self) -> Self {
+        Self::ALL[(self as usize + 2) % 5]
+    }
+}
+
+impl Can {
+    /// Get the element of the stem, two consecutive stems share each element.
+    pub fn element(self) -> Element {
+        Element::ALL[self.index() / 2]
+    }
+}
+
+/// Relation between the elements of two stems.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ElementRelation {
+    /// One element generates the other (tương sinh).
+    Sinh,
+    /// One element overcomes the other (tương khắc).
+    Khac,
+    /// Both stems have the same element.
+    TrungTinh,
+}
+
+/// Get the relation between the elements of two stems.
+///
+/// The relation is symmetric, use [`Element::generates`] and [`Element::overcomes`] to know
+/// which stem acts on the other.
+pub fn element_relation(a: Can, b: Can) -> ElementRelation {
+    let (a, b) = (a.element(), b.element());
+    if a == b {
+        ElementRelation::TrungTinh
+    } else if a.generates() == b || b.generates() == a {
+        ElementRelation::Sinh
+    } else {
+        ElementRelation::Khac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element() {
+        assert_eq!(Can::Giap.element(), Element::Moc);
+        assert_eq!(Can::Dinh.element(), Element::Hoa);
+        assert_eq!(Can::Ky.element(), Element::Tho);
+        assert_eq!(Can::Canh.element(), Element::Kim);
+        assert_eq!(Can::Quy.element(), Element::Thuy);
+        assert_eq!(Element::Thuy.generates(), Element::Moc);
+        assert_eq!(Element::Thuy.overcomes(), Element::Hoa);
+        assert_eq!(Element::Kim.overcomes(), Element::Moc);
+    }
+
+    #[test]
+    fn test_element_relation() {
+        assert_eq!(
+            element_relation(Can::Giap, Can::At),
+            ElementRelation::TrungTinh
+        );
+        assert_eq!(
+            element_relation(Can::Giap, Can::Binh),
+            ElementRelation::Sinh
+        );
+        assert_eq!(
+            element_relation(Can::Binh, Can::Giap),
+            ElementRelation::Sinh
+        );
+        assert_eq!(
+            element_relation(Can::Canh, Can::Giap),
+            ElementRelation::Khac
+        );
+        assert_eq!(
+            element_relation(Can::Giap, Can::Canh),
+            ElementRelation::Khac
+        );
+        assert_eq!(
+            element_relation(Can::Nham, Can::Dinh),
+            ElementRelation::Khac
+        );
+        for a in (0..10).map(Can::from_index) {
+            for b in (0..10).map(Can::from_index) {
+                assert_eq!(element_relation(a, b), element_relation(b, a));
+            }
+        }
+    }
+}
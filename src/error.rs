@@ -0,0 +1,92 @@
+//! The [`LichTaError`] type.
+
+use core::fmt;
+
+/// Error returned by the fallible LichTa APIs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LichTaError {
+    /// The year is outside the range supported by [`Date`](crate::Date).
+    DateOutOfRange,
+    /// The day does not exist in the month.
+    DayOutOfRange { day: i32, month_length: i32 },
+    /// The text is not in the format the parser expects.
+    InvalidFormat,
+    /// The month is not in `1..=12`.
+    InvalidMonth(i32),
+    /// The timezone is not a finite offset from UTC within a day.
+    InvalidTimezone,
+    /// The lunar year has no leap month with this number.
+    NoSuchLeapMonth { month: i32, year: i32 },
+}
+
+impl fmt::Display for LichTaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DateOutOfRange => f.write_str("date is outside the supported range"),
+            Self::DayOutOfRange { day, month_length } => write!(
+                f,
+                "day {day} does not exist in a month of {month_length} days"
+            ),
+            Self::InvalidFormat => f.write_str("text is not a valid lunar date"),
+            Self::InvalidMonth(month) => write!(f, "month {month} is not between 1 and 12"),
+            Self::InvalidTimezone => f.write_str("timezone is not a valid offset from UTC"),
+            Self::NoSuchLeapMonth { month, year } => {
+                write!(f, "lunar year {year} has no leap month {month}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for LichTaError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display() {
+        use alloc::string::ToString;
+
+        assert_eq!(
+            LichTaError::DateOutOfRange.to_string(),
+            "date is outside the supported range"
+        );
+        assert_eq!(
+            LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            }
+            .to_string(),
+            "day 30 does not exist in a month of 29 days"
+        );
+        assert_eq!(
+            LichTaError::InvalidFormat.to_string(),
+            "text is not a valid lunar date"
+        );
+        assert_eq!(
+            LichTaError::InvalidMonth(13).to_string(),
+            "month 13 is not between 1 and 12"
+        );
+        assert_eq!(
+            LichTaError::InvalidTimezone.to_string(),
+            "timezone is not a valid offset from UTC"
+        );
+        assert_eq!(
+            LichTaError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            }
+            .to_string(),
+            "lunar year 2025 has no leap month 5"
+        );
+    }
+
+    #[test]
+    fn test_error_source() {
+        use core::error::Error;
+
+        let error: &dyn Error = &LichTaError::DateOutOfRange;
+        assert!(error.source().is_none());
+    }
+}
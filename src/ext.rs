@@ -0,0 +1,51 @@
+//! The [`DateLichTaExt`] extension trait on [`Date`].
+
+use crate::{day_can_chi, Can, Chi, Date, NgayTa};
+
+/// Conversions callable on a [`Date`], e.g. `date.to_lichta(Timezone::VIETNAM)`.
+///
+/// The timezone is anything convertible to hours from UTC: a [`Timezone`](crate::Timezone)
+/// or an `f64`.
+///
+/// ```
+/// use lich_ta::{Date, DateLichTaExt, NgayTa, Timezone};
+///
+/// let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+/// assert_eq!(date.to_lichta(Timezone::VIETNAM), NgayTa::new(1, 1, 2024, false));
+/// ```
+pub trait DateLichTaExt {
+    /// Convert the date to the LichTa calendar, same as [`NgayTa::from_date`].
+    fn to_lichta(self, timezone: impl Into<f64>) -> NgayTa;
+    /// Convert the date to the LichTa calendar, along with the Can Chi of the day.
+    fn lunar_day_can_chi(self, timezone: impl Into<f64>) -> (NgayTa, (Can, Chi));
+}
+
+impl DateLichTaExt for Date {
+    fn to_lichta(self, timezone: impl Into<f64>) -> NgayTa {
+        NgayTa::from_date(self, timezone.into())
+    }
+    fn lunar_day_can_chi(self, timezone: impl Into<f64>) -> (NgayTa, (Can, Chi)) {
+        (self.to_lichta(timezone), day_can_chi(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timezone;
+
+    #[test]
+    fn test_date_lichta_ext() {
+        let date = Date::from_calendar_date(2025, time::Month::July, 25).unwrap();
+        assert_eq!(date.to_lichta(7.0), NgayTa::from_date(date, 7.0));
+        assert_eq!(
+            date.to_lichta(Timezone::VIETNAM),
+            NgayTa::new(1, 6, 2025, true)
+        );
+        let tet = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        assert_eq!(
+            tet.lunar_day_can_chi(Timezone::VIETNAM),
+            (NgayTa::new(1, 1, 2024, false), (Can::Giap, Chi::Thin))
+        );
+    }
+}
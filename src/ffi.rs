@@ -0,0 +1,140 @@
+//! C ABI for callers outside Rust, e.g. Swift or Kotlin through a C header.
+//!
+//! Build the crate as a `staticlib` or `cdylib` with the `ffi` feature and declare:
+//!
+//! ```c
+//! int32_t lichta_convert(int32_t year, int32_t month, int32_t day, double timezone,
+//!                        int32_t *out_day, int32_t *out_month, int32_t *out_year,
+//!                        int32_t *out_leap);
+//! ```
+//!
+//! Invalid arguments do not panic across the boundary, they are reported as one of the
+//! negative `LICHTA_ERR_*` codes.
+
+use crate::{LichTaError, NgayTa};
+
+/// The call succeeded and the outputs were written.
+pub const LICHTA_OK: i32 = 0;
+/// An output pointer is null.
+pub const LICHTA_ERR_NULL_POINTER: i32 = -1;
+/// The month is not in `1..=12`.
+pub const LICHTA_ERR_INVALID_MONTH: i32 = -2;
+/// The day does not exist in the month.
+pub const LICHTA_ERR_DAY_OUT_OF_RANGE: i32 = -3;
+/// The date is outside the supported range, see [`supported_date_range`](crate::supported_date_range).
+pub const LICHTA_ERR_DATE_OUT_OF_RANGE: i32 = -4;
+/// The timezone is not a finite number.
+pub const LICHTA_ERR_INVALID_TIMEZONE: i32 = -5;
+
+impl LichTaError {
+    /// Error code of the C ABI.
+    fn code(self) -> i32 {
+        match self {
+            LichTaError::DateOutOfRange => LICHTA_ERR_DATE_OUT_OF_RANGE,
+            LichTaError::DayOutOfRange { .. } => LICHTA_ERR_DAY_OUT_OF_RANGE,
+            LichTaError::InvalidTimezone => LICHTA_ERR_INVALID_TIMEZONE,
+            // Only the month can be invalid in a conversion, there is no text or leap flag
+            LichTaError::InvalidMonth(_)
+            | LichTaError::NoSuchLeapMonth { .. }
+            | LichTaError::InvalidFormat => LICHTA_ERR_INVALID_MONTH,
+        }
+    }
+}
+
+/// Convert a Gregorian date to the LichTa calendar.
+///
+/// Parameters:
+/// - `year`, `month`, `day`: Gregorian date, `month` from 1 to 12.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+/// - `out_day`, `out_month`, `out_year`: Lunar date, written on success only.
+/// - `out_leap`: 1 for a leap month else 0, written on success only.
+///
+/// Returns: [`LICHTA_OK`], or one of the negative `LICHTA_ERR_*` codes.
+///
+/// # Safety
+///
+/// Each output pointer must be null or valid for writing an `i32`.
+#[no_mangle]
+pub unsafe extern "C" fn lichta_convert(
+    year: i32,
+    month: i32,
+    day: i32,
+    timezone: f64,
+    out_day: *mut i32,
+    out_month: *mut i32,
+    out_year: *mut i32,
+    out_leap: *mut i32,
+) -> i32 {
+    if out_day.is_null() || out_month.is_null() || out_year.is_null() || out_leap.is_null() {
+        return LICHTA_ERR_NULL_POINTER;
+    }
+    if !timezone.is_finite() {
+        return LICHTA_ERR_INVALID_TIMEZONE;
+    }
+    let Ok(month) = u8::try_from(month) else {
+        return LICHTA_ERR_INVALID_MONTH;
+    };
+    let Ok(day) = u8::try_from(day) else {
+        return LICHTA_ERR_DAY_OUT_OF_RANGE;
+    };
+    match NgayTa::from_ymd(year, month, day, timezone) {
+        Ok(ngay_ta) => {
+            // SAFETY: the pointers are not null and the caller guarantees they are writable
+            unsafe {
+                out_day.write(ngay_ta.day());
+                out_month.write(ngay_ta.month());
+                out_year.write(ngay_ta.year());
+                out_leap.write(i32::from(ngay_ta.is_leap_month()));
+            }
+            LICHTA_OK
+        }
+        Err(error) => error.code(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    fn convert(year: i32, month: i32, day: i32) -> Result<(i32, i32, i32, i32), i32> {
+        let (mut d, mut m, mut y, mut leap) = (0, 0, 0, 0);
+        let code =
+            unsafe { lichta_convert(year, month, day, 7.0, &mut d, &mut m, &mut y, &mut leap) };
+        if code == LICHTA_OK {
+            Ok((d, m, y, leap))
+        } else {
+            Err(code)
+        }
+    }
+
+    #[test]
+    fn test_lichta_convert() {
+        assert_eq!(convert(2025, 7, 25), Ok((1, 6, 2025, 1)));
+        assert_eq!(convert(2024, 2, 10), Ok((1, 1, 2024, 0)));
+        assert_eq!(convert(2024, 13, 1), Err(LICHTA_ERR_INVALID_MONTH));
+        assert_eq!(convert(2024, -1, 1), Err(LICHTA_ERR_INVALID_MONTH));
+        assert_eq!(convert(2023, 2, 29), Err(LICHTA_ERR_DAY_OUT_OF_RANGE));
+        assert_eq!(convert(2023, 2, 300), Err(LICHTA_ERR_DAY_OUT_OF_RANGE));
+        let beyond = crate::supported_date_range().end().next_day().unwrap();
+        assert_eq!(
+            convert(
+                beyond.year(),
+                u8::from(beyond.month()).into(),
+                beyond.day().into()
+            ),
+            Err(LICHTA_ERR_DATE_OUT_OF_RANGE)
+        );
+    }
+
+    #[test]
+    fn test_lichta_convert_invalid_arguments() {
+        let mut out = [0; 4];
+        let [d, m, y, leap] = out.each_mut();
+        let code = unsafe { lichta_convert(2024, 1, 1, 7.0, ptr::null_mut(), m, y, leap) };
+        assert_eq!(code, LICHTA_ERR_NULL_POINTER);
+        let code = unsafe { lichta_convert(2024, 1, 1, f64::NAN, d, m, y, leap) };
+        assert_eq!(code, LICHTA_ERR_INVALID_TIMEZONE);
+        assert_eq!(out, [0; 4]);
+    }
+}
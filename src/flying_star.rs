@@ -0,0 +1,43 @@
+//! The annual flying star (cửu tinh, huyền không phi tinh) of feng shui.
+
+/// Get the annual flying star of a lunar year, from 1 to 9.
+///
+/// The star at the center of the Lo Shu square goes down by one each year and wraps from 1 to
+/// 9, with 1 in year 1, hence `star = (10 - year) mod 9 + 1`: 2024 is 3, 2025 is 2
+/// and 2026 is 1.
+///
+/// The stars are usually changed at Lập Xuân rather than at Tết, pass the year that began at
+/// the one in use.
+///
+/// Parameters:
+/// - `lunar_year`: Lunar year.
+pub fn annual_flying_star(lunar_year: i32) -> u8 {
+    ((10 - i64::from(lunar_year)).rem_euclid(9) + 1) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annual_flying_star() {
+        for (year, star) in [
+            (1900, 1),
+            (1984, 7),
+            (2000, 9),
+            (2017, 1),
+            (2023, 4),
+            (2024, 3),
+            (2025, 2),
+            (2026, 1),
+            (2027, 9),
+        ] {
+            assert_eq!(annual_flying_star(year), star, "{year}");
+        }
+        // The cycle repeats every 9 years, before year 0 too
+        for year in [-20, -1, 0, 1, i32::MIN, i32::MAX - 9] {
+            assert_eq!(annual_flying_star(year), annual_flying_star(year + 9));
+            assert!((1..=9).contains(&annual_flying_star(year)));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Textual forms of a [`NgayTa`]: [`Display`](fmt::Display) and its [`FromStr`] parser, and
+//! the ISO-like form.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{LichTaError, NgayTa};
+
+/// Marker after the month of a leap month in the `Display` form, e.g. "1/6+/2025".
+const LEAP_SUFFIX: char = '+';
+/// Marker after the month of a leap month in the ISO-like form, e.g. "2025-06L-01".
+const ISO_LEAP_SUFFIX: char = 'L';
+
+/// Parse a month, checking it is in `1..=12`.
+fn parse_month(text: &str) -> Result<i32, LichTaError> {
+    let month = text.parse().map_err(|_| LichTaError::InvalidFormat)?;
+    if !(1..=12).contains(&month) {
+        return Err(LichTaError::InvalidMonth(month));
+    }
+    Ok(month)
+}
+
+/// Parse a day, checking it is in `1..=30`.
+fn parse_day(text: &str) -> Result<i32, LichTaError> {
+    let day = text.parse().map_err(|_| LichTaError::InvalidFormat)?;
+    if !(1..=30).contains(&day) {
+        return Err(LichTaError::InvalidFormat);
+    }
+    Ok(day)
+}
+
+/// Writes the date as `day/month/year`, with a `+` after the month of a leap month:
+/// "17/4/2024", "1/6+/2025".
+impl fmt::Display for NgayTa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.day(), self.month())?;
+        if self.is_leap_month() {
+            write!(f, "{LEAP_SUFFIX}")?;
+        }
+        write!(f, "/{}", self.year())
+    }
+}
+
+/// Parses the [`Display`](fmt::Display) form back.
+///
+/// The fields are only checked against their ranges, use [`NgayTa::is_valid`] to check the
+/// date against the calendar.
+impl FromStr for NgayTa {
+    type Err = LichTaError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parts = text.split('/');
+        let (Some(day), Some(month), Some(year), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(LichTaError::InvalidFormat);
+        };
+        let (month, is_leap_month) = match month.strip_suffix(LEAP_SUFFIX) {
+            Some(month) => (month, true),
+            None => (month, false),
+        };
+        Ok(Self::new(
+            parse_day(day)?,
+            parse_month(month)?,
+            year.parse().map_err(|_| LichTaError::InvalidFormat)?,
+            is_leap_month,
+        ))
+    }
+}
+
+/// ISO-like form of a [`NgayTa`], see [`NgayTa::iso_like`].
+#[derive(Clone, Copy, Debug)]
+pub struct IsoLike(NgayTa);
+
+impl fmt::Display for IsoLike {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = self.0.year();
+        if year < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{:04}-{:02}", year.unsigned_abs(), self.0.month())?;
+        if self.0.is_leap_month() {
+            write!(f, "{ISO_LEAP_SUFFIX}")?;
+        }
+        write!(f, "-{:02}", self.0.day())
+    }
+}
+
+impl NgayTa {
+    /// Get the date in an ISO 8601-like `YYYY-MM-DD` form, e.g. "2024-04-17".
+    ///
+    /// A leap month is followed by `L`, e.g. "2025-06L-01". This form sorts as text like the
+    /// dates do, for years 0 to 9999.
+    pub fn iso_like(&self) -> IsoLike {
+        IsoLike(*self)
+    }
+    /// Same as [`NgayTa::iso_like`], as a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso_like(&self) -> alloc::string::String {
+        alloc::string::ToString::to_string(&self.iso_like())
+    }
+    /// Parse the form written by [`NgayTa::iso_like`].
+    ///
+    /// The fields are only checked against their ranges, use [`NgayTa::is_valid`] to check the
+    /// date against the calendar.
+    pub fn from_iso_like(text: &str) -> Result<Self, LichTaError> {
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => (-1, unsigned),
+            None => (1, text),
+        };
+        let mut parts = unsigned.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(LichTaError::InvalidFormat);
+        };
+        let year: i32 = year.parse().map_err(|_| LichTaError::InvalidFormat)?;
+        let (month, is_leap_month) = match month.strip_suffix(ISO_LEAP_SUFFIX) {
+            Some(month) => (month, true),
+            None => (month, false),
+        };
+        Ok(Self::new(
+            parse_day(day)?,
+            parse_month(month)?,
+            sign * year,
+            is_leap_month,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    const DATES: [NgayTa; 4] = [
+        NgayTa::new_unchecked(17, 4, 2024, false),
+        NgayTa::new_unchecked(1, 6, 2025, true),
+        NgayTa::new_unchecked(30, 12, 1900, false),
+        NgayTa::new_unchecked(29, 2, -1, true),
+    ];
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display() {
+        assert_eq!(alloc::format!("{}", DATES[0]), "17/4/2024");
+        assert_eq!(alloc::format!("{}", DATES[1]), "1/6+/2025");
+        assert_eq!(DATES[0].to_iso_like(), "2024-04-17");
+        assert_eq!(DATES[1].to_iso_like(), "2025-06L-01");
+        assert_eq!(DATES[3].to_iso_like(), "-0001-02L-29");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_all_forms_keep_leap_month() {
+        for ngay_ta in DATES {
+            let display = alloc::format!("{ngay_ta}");
+            assert_eq!(display.parse::<NgayTa>(), Ok(ngay_ta), "{display}");
+            let iso_like = ngay_ta.to_iso_like();
+            assert_eq!(NgayTa::from_iso_like(&iso_like), Ok(ngay_ta), "{iso_like}");
+            let hanzi = ngay_ta.to_hanzi().unwrap();
+            assert_eq!(NgayTa::from_hanzi(&hanzi), Ok(ngay_ta), "{hanzi}");
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("17/4".parse::<NgayTa>(), Err(LichTaError::InvalidFormat));
+        assert_eq!(
+            "17/4/2024/1".parse::<NgayTa>(),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            "17/13/2024".parse::<NgayTa>(),
+            Err(LichTaError::InvalidMonth(13))
+        );
+        assert_eq!(
+            "31/4/2024".parse::<NgayTa>(),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            "17/4*/2024".parse::<NgayTa>(),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            NgayTa::from_iso_like("2024-04"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            NgayTa::from_iso_like("2024-00-17"),
+            Err(LichTaError::InvalidMonth(0))
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Rendering of [`NgayTa`] in Chinese characters, and parsing it back.
+//!
+//! Optional interop for users reading the same calendar in Chinese, e.g. "二〇二四年四月十七".
+
+use alloc::string::String;
+
+use crate::{LichTaError, NgayTa};
+
+const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const MONTHS: [&str; 12] = [
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二",
+];
+const LEAP_PREFIX: char = '閏';
+
+/// Write `year` digit by digit, e.g. 2024 as "二〇二四".
+fn push_year(output: &mut String, year: i32) {
+    if year < 0 {
+        output.push('-');
+    }
+    let year = year.unsigned_abs();
+    let mut divisor = 1;
+    while divisor * 10 <= year {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        output.push(DIGITS[(year / divisor % 10) as usize]);
+        divisor /= 10;
+    }
+}
+
+/// Write `day` with the traditional day names: 初一 to 初十, 十一 to 十九, 二十, 廿一 to 廿九, 三十.
+fn push_day(output: &mut String, day: i32) {
+    let (tens, units) = (day / 10, (day % 10) as usize);
+    match (tens, units) {
+        (0, _) => {
+            output.push('初');
+            output.push(DIGITS[units]);
+        }
+        (1, 0) => output.push_str("初十"),
+        (2, 0) => output.push_str("二十"),
+        (3, 0) => output.push_str("三十"),
+        (1, _) => {
+            output.push('十');
+            output.push(DIGITS[units]);
+        }
+        _ => {
+            output.push('廿');
+            output.push(DIGITS[units]);
+        }
+    }
+}
+
+/// Get the value of a digit written by [`push_year`].
+fn digit_value(digit: char) -> Option<u32> {
+    DIGITS.iter().position(|&d| d == digit).map(|i| i as u32)
+}
+
+/// Read a year written by [`push_year`].
+fn parse_year(text: &str) -> Option<i32> {
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => (-1, digits),
+        None => (1, text),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let year = digits.chars().try_fold(0_i32, |year, digit| {
+        year.checked_mul(10)?
+            .checked_add(digit_value(digit)? as i32)
+    })?;
+    Some(sign * year)
+}
+
+/// Read a day written by [`push_day`].
+fn parse_day(text: &str) -> Option<i32> {
+    let mut chars = text.chars();
+    let (first, second) = (chars.next()?, chars.next()?);
+    if chars.next().is_some() {
+        return None;
+    }
+    let day = match (first, second) {
+        ('初', '十') => 10,
+        ('二', '十') => 20,
+        ('三', '十') => 30,
+        ('初', units) => digit_value(units)? as i32,
+        ('十', units) => 10 + digit_value(units)? as i32,
+        ('廿', units) => 20 + digit_value(units)? as i32,
+        _ => return None,
+    };
+    (1..=30).contains(&day).then_some(day)
+}
+
+impl NgayTa {
+    /// Render the date in Chinese characters, e.g. "二〇二四年四月十七".
+    ///
+    /// Leap months are prefixed with 閏, e.g. "二〇二五年閏六月初一".
+    ///
+    /// Only the ranges of the fields are checked, call [`NgayTa::is_valid`] first to check the
+    /// date against the calendar.
+    ///
+    /// Returns: [`LichTaError::InvalidMonth`] for a month outside `1..=12`, or
+    /// [`LichTaError::DayOutOfRange`] for a day outside `1..=30`, which have no characters.
+    pub fn to_hanzi(&self) -> Result<String, LichTaError> {
+        let month = usize::try_from(self.month() - 1)
+            .ok()
+            .and_then(|index| MONTHS.get(index))
+            .ok_or(LichTaError::InvalidMonth(self.month()))?;
+        if !(1..=30).contains(&self.day()) {
+            return Err(LichTaError::DayOutOfRange {
+                day: self.day(),
+                month_length: 30,
+            });
+        }
+        let mut output = String::new();
+        push_year(&mut output, self.year());
+        output.push('年');
+        if self.is_leap_month() {
+            output.push(LEAP_PREFIX);
+        }
+        output.push_str(month);
+        output.push('月');
+        push_day(&mut output, self.day());
+        Ok(output)
+    }
+    /// Parse the form written by [`NgayTa::to_hanzi`], leap month prefix included.
+    ///
+    /// The fields are only checked against their ranges, use [`NgayTa::is_valid`] to check the
+    /// date against the calendar.
+    pub fn from_hanzi(text: &str) -> Result<Self, LichTaError> {
+        let (year, rest) = text.split_once('年').ok_or(LichTaError::InvalidFormat)?;
+        let (month, day) = rest.split_once('月').ok_or(LichTaError::InvalidFormat)?;
+        let (month, is_leap_month) = match month.strip_prefix(LEAP_PREFIX) {
+            Some(month) => (month, true),
+            None => (month, false),
+        };
+        let month = MONTHS
+            .iter()
+            .position(|&m| m == month)
+            .ok_or(LichTaError::InvalidFormat)?;
+        Ok(Self::new(
+            parse_day(day).ok_or(LichTaError::InvalidFormat)?,
+            month as i32 + 1,
+            parse_year(year).ok_or(LichTaError::InvalidFormat)?,
+            is_leap_month,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_hanzi() {
+        assert_eq!(
+            NgayTa::new(17, 4, 2024, false).to_hanzi(),
+            Ok("二〇二四年四月十七".into())
+        );
+        assert_eq!(
+            NgayTa::new(1, 6, 2025, true).to_hanzi(),
+            Ok("二〇二五年閏六月初一".into())
+        );
+        assert_eq!(
+            NgayTa::new(10, 1, 2025, false).to_hanzi(),
+            Ok("二〇二五年正月初十".into())
+        );
+        assert_eq!(
+            NgayTa::new(20, 11, 2025, false).to_hanzi(),
+            Ok("二〇二五年十一月二十".into())
+        );
+        assert_eq!(
+            NgayTa::new(29, 12, 2025, false).to_hanzi(),
+            Ok("二〇二五年十二月廿九".into())
+        );
+        assert_eq!(
+            NgayTa::new(30, 10, 1900, false).to_hanzi(),
+            Ok("一九〇〇年十月三十".into())
+        );
+    }
+
+    #[test]
+    fn test_to_hanzi_out_of_range() {
+        assert_eq!(
+            NgayTa::new(1, 13, 2024, false).to_hanzi(),
+            Err(LichTaError::InvalidMonth(13))
+        );
+        assert_eq!(
+            NgayTa::new(1, 0, 2024, false).to_hanzi(),
+            Err(LichTaError::InvalidMonth(0))
+        );
+        assert_eq!(
+            NgayTa::new(0, 4, 2024, false).to_hanzi(),
+            Err(LichTaError::DayOutOfRange {
+                day: 0,
+                month_length: 30
+            })
+        );
+        assert_eq!(
+            NgayTa::new(31, 4, 2024, false).to_hanzi(),
+            Err(LichTaError::DayOutOfRange {
+                day: 31,
+                month_length: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_hanzi() {
+        assert_eq!(
+            NgayTa::from_hanzi("二〇二四年四月十七"),
+            Ok(NgayTa::new(17, 4, 2024, false))
+        );
+        assert_eq!(
+            NgayTa::from_hanzi("二〇二五年閏六月初一"),
+            Ok(NgayTa::new(1, 6, 2025, true))
+        );
+        assert_eq!(
+            NgayTa::from_hanzi("二〇二五年十二月廿九"),
+            Ok(NgayTa::new(29, 12, 2025, false))
+        );
+        for day in 1..=30 {
+            let ngay_ta = NgayTa::new(day, 11, 1900, false);
+            assert_eq!(
+                NgayTa::from_hanzi(&ngay_ta.to_hanzi().unwrap()),
+                Ok(ngay_ta)
+            );
+        }
+        assert_eq!(
+            NgayTa::from_hanzi("二〇二五年六月"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            NgayTa::from_hanzi("二〇二五年十三月初一"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            NgayTa::from_hanzi("年六月初一"),
+            Err(LichTaError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_name_tables() {
+        assert_eq!((DIGITS.len(), MONTHS.len()), (10, 12));
+        for (i, digit) in DIGITS.iter().enumerate() {
+            assert!(!DIGITS[..i].contains(digit), "{digit} is repeated");
+        }
+        for (i, month) in MONTHS.iter().enumerate() {
+            assert!(!month.is_empty());
+            assert!(!MONTHS[..i].contains(month), "{month} is repeated");
+        }
+        assert_eq!((MONTHS[0], MONTHS[11]), ("正", "十二"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Auspicious hours (giờ hoàng đạo) of a day.
+
+use crate::can_chi::{day_can_chi, Chi};
+use crate::Date;
+
+/// Positions of the 6 auspicious gods among the 12 of the hours, counted from Thanh Long:
+/// Thanh Long, Minh Đường, Kim Quỹ, Bảo Quang, Ngọc Đường and Tư Mệnh.
+const HOANG_DAO_OFFSETS: [i32; 6] = [0, 1, 4, 5, 7, 10];
+
+/// Get the Chi of the 6 auspicious hours of a day, in the order of the day from Tý.
+///
+/// The 12 gods of the hours start with Thanh Long at the Thân hour on Tý and Ngọ days, two
+/// hours later on each following pair of days. Like [`day_can_chi`], it does not depend on
+/// the timezone.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+pub fn auspicious_hours(date: Date) -> [Chi; 6] {
+    let (_, day_chi) = day_can_chi(date);
+    let thanh_long = 8 + 2 * (day_chi.index() as i32 % 6);
+    let mut hours = HOANG_DAO_OFFSETS.map(|offset| Chi::from_index(thanh_long + offset));
+    hours.sort();
+    hours
+}
+
+/// Get the clock times of the auspicious hours of a day, e.g. `(23, 1)` for 23h-1h.
+///
+/// Each hour of the Chi cycle spans two clock hours, the Tý hour from 23:00 to 01:00. The
+/// ranges are in the order of [`auspicious_hours`].
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+///
+/// Returns: (start, end) local hours of each range, the end excluded.
+pub fn auspicious_hour_ranges(date: Date) -> [(u8, u8); 6] {
+    auspicious_hours(date).map(|chi| {
+        let index = chi.index() as u8;
+        ((index * 2 + 23) % 24, index * 2 + 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_auspicious_hours() {
+        // Tết Giáp Thìn was a Thìn day
+        let date = Date::from_calendar_date(2024, Month::February, 10).unwrap();
+        assert_eq!(
+            auspicious_hours(date),
+            [Chi::Dan, Chi::Thin, Chi::Ti, Chi::Than, Chi::Dau, Chi::Hoi]
+        );
+        // 2000-01-01 was a Ngọ day
+        let date = Date::from_calendar_date(2000, Month::January, 1).unwrap();
+        assert_eq!(
+            auspicious_hours(date),
+            [Chi::Ty, Chi::Suu, Chi::Mao, Chi::Ngo, Chi::Than, Chi::Dau]
+        );
+    }
+
+    #[test]
+    fn test_auspicious_hour_ranges() {
+        let date = Date::from_calendar_date(2000, Month::January, 1).unwrap();
+        assert_eq!(
+            auspicious_hour_ranges(date),
+            [(23, 1), (1, 3), (5, 7), (11, 13), (15, 17), (17, 19)]
+        );
+    }
+}
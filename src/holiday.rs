@@ -0,0 +1,161 @@
+//! Traditional holidays of the LichTa calendar.
+
+use crate::solar_term::days_into_solar_term;
+use crate::{Date, NgayTa, SolarTerm};
+
+/// Traditional holiday falling on a fixed lunar date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Holiday {
+    /// Lunar New Year, 1/1.
+    TetNguyenDan,
+    /// First full moon of the year, 15/1.
+    TetNguyenTieu,
+    /// Cold food festival, 3/3.
+    TetHanThuc,
+    /// Hung Kings commemoration, 10/3.
+    GioToHungVuong,
+    /// Buddha's birthday, 15/4.
+    PhatDan,
+    /// Mid-year festival, 5/5.
+    TetDoanNgo,
+    /// Ghost festival, 15/7.
+    VuLan,
+    /// Mid-autumn festival, 15/8.
+    TetTrungThu,
+    /// Kitchen gods' day, 23/12.
+    OngCongOngTao,
+}
+
+impl Holiday {
+    /// Vietnamese name, e.g. "Tết Trung Thu".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Holiday::TetNguyenDan => "Tết Nguyên Đán",
+            Holiday::TetNguyenTieu => "Tết Nguyên Tiêu",
+            Holiday::TetHanThuc => "Tết Hàn Thực",
+            Holiday::GioToHungVuong => "Giỗ Tổ Hùng Vương",
+            Holiday::PhatDan => "Lễ Phật Đản",
+            Holiday::TetDoanNgo => "Tết Đoan Ngọ",
+            Holiday::VuLan => "Lễ Vu Lan",
+            Holiday::TetTrungThu => "Tết Trung Thu",
+            Holiday::OngCongOngTao => "Ông Công Ông Táo",
+        }
+    }
+}
+
+/// Get the holiday falling on a lunar date, if any.
+///
+/// Holidays are never observed in a leap month.
+pub fn lunar_holiday(ngay_ta: &NgayTa) -> Option<Holiday> {
+    if ngay_ta.is_leap_month() {
+        return None;
+    }
+    let holiday = match (ngay_ta.day(), ngay_ta.month()) {
+        (1, 1) => Holiday::TetNguyenDan,
+        (15, 1) => Holiday::TetNguyenTieu,
+        (3, 3) => Holiday::TetHanThuc,
+        (10, 3) => Holiday::GioToHungVuong,
+        (15, 4) => Holiday::PhatDan,
+        (5, 5) => Holiday::TetDoanNgo,
+        (15, 7) => Holiday::VuLan,
+        (15, 8) => Holiday::TetTrungThu,
+        (23, 12) => Holiday::OngCongOngTao,
+        _ => return None,
+    };
+    Some(holiday)
+}
+
+impl NgayTa {
+    /// Whether the date is Tết Nguyên Đán, day 1 of month 1.
+    pub fn is_tet(&self) -> bool {
+        self.is_tet_period(1)
+    }
+    /// Whether the date is within the first `days` days of month 1, the New Year holiday,
+    /// e.g. `is_tet_period(3)` for the three days of Tết.
+    ///
+    /// Rằm tháng Giêng, day 15, is a separate holiday and only counts if `days` reaches it.
+    pub fn is_tet_period(&self, days: i32) -> bool {
+        !self.is_leap_month() && self.month() == 1 && (1..=days).contains(&self.day())
+    }
+}
+
+/// Get the Gregorian date of Thanh Minh, the day the sun reaches 15° of longitude.
+///
+/// Unlike the holidays of [`lunar_holiday`], it follows the solar terms and falls on April 4,
+/// 5 or 6, whatever the lunar date.
+///
+/// Parameters:
+/// - `year`: Gregorian year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if `year` is outside the range supported by [`Date`].
+pub fn thanh_minh(year: i32, timezone: f64) -> Date {
+    // Thanh Minh lasts until Cốc Vũ around April 20, April 10 is always within it
+    let date =
+        Date::from_calendar_date(year, time::Month::April, 10).expect("Invalid date for year");
+    let (term, days) = days_into_solar_term(date, timezone);
+    debug_assert_eq!(term, SolarTerm::ThanhMinh);
+    date - time::Duration::days(i64::from(days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lunar_holiday() {
+        assert_eq!(
+            lunar_holiday(&NgayTa::new(1, 1, 2025, false)),
+            Some(Holiday::TetNguyenDan)
+        );
+        assert_eq!(
+            lunar_holiday(&NgayTa::new(15, 8, 2025, false)),
+            Some(Holiday::TetTrungThu)
+        );
+        assert_eq!(lunar_holiday(&NgayTa::new(15, 6, 2025, true)), None);
+        assert_eq!(lunar_holiday(&NgayTa::new(2, 1, 2025, false)), None);
+    }
+
+    #[test]
+    fn test_is_tet() {
+        assert!(NgayTa::new(1, 1, 2025, false).is_tet());
+        assert!(!NgayTa::new(2, 1, 2025, false).is_tet());
+        assert!(!NgayTa::new(1, 2, 2025, false).is_tet());
+        for day in 1..=3 {
+            let ngay_ta = NgayTa::new(day, 1, 2025, false);
+            assert!(ngay_ta.is_tet_period(3));
+            assert!(!ngay_ta.is_tet_period(0));
+            assert_eq!(ngay_ta.is_tet_period(1), day == 1);
+        }
+        assert!(!NgayTa::new(4, 1, 2025, false).is_tet_period(3));
+        // Rằm tháng Giêng is a holiday of its own
+        let ram = NgayTa::new(15, 1, 2025, false);
+        assert!(!ram.is_tet_period(3));
+        assert_eq!(lunar_holiday(&ram), Some(Holiday::TetNguyenTieu));
+        // Day 1 of a leap month 1 is not Tết
+        assert!(!NgayTa::new(1, 1, 2025, true).is_tet_period(3));
+    }
+
+    #[test]
+    fn test_thanh_minh() {
+        for (year, day) in [
+            (2008, 4),
+            (2015, 5),
+            (2023, 5),
+            (2024, 4),
+            (2025, 4),
+            (2026, 5),
+        ] {
+            assert_eq!(
+                thanh_minh(year, 7.0),
+                Date::from_calendar_date(year, time::Month::April, day).unwrap()
+            );
+        }
+        assert_eq!(
+            crate::solar_term_starting(thanh_minh(1990, 7.0), 7.0),
+            Some(SolarTerm::ThanhMinh)
+        );
+    }
+}
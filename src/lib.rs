@@ -1,10 +1,117 @@
 //! Tools for Lichta Calendar. Compatible with `#![no_std]`.
 
 #![no_std]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod anniversary;
+mod builder;
+mod bytes;
+mod can_chi;
+mod converter;
+mod day_indices;
+mod details;
+mod direction;
+mod element;
+mod error;
+mod ext;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod flying_star;
+mod format;
+#[cfg(feature = "alloc")]
+mod hanzi;
+mod hoang_dao;
+mod holiday;
+mod lunar_month;
+mod lunar_year;
+mod mansion;
+mod meridian;
+#[cfg(feature = "alloc")]
+mod month_grid;
+mod month_name;
+mod moon;
+#[cfg(feature = "alloc")]
+mod named;
 mod ngay_ta;
+#[cfg(feature = "high-accuracy")]
+mod refine;
+mod season;
+mod sexagenary;
+mod solar_term;
+#[cfg(feature = "solar-time")]
+mod solar_time;
+mod tet;
+mod timezone;
+mod truc;
+mod tuan;
+mod tuple;
 mod util;
+mod vietnamese;
+#[cfg(feature = "alloc")]
+mod year_calendar;
+mod zodiac;
+mod zoned;
+#[cfg(feature = "alloc")]
+pub use anniversary::{anniversaries_in_range, solar_birthday_matches_lunar};
+pub use builder::NgayTaBuilder;
+pub use can_chi::{
+    day_can_chi, day_hour_base_can, hour_can_chi, month_can_chi, year_can_chi, Can, Chi, CAN_NAMES,
+    CHI_NAMES,
+};
+#[cfg(feature = "alloc")]
+pub use converter::{convert_lunar_dates, convert_multi_tz};
+pub use converter::{Converter, SequentialConverter};
+pub use day_indices::DayIndices;
+pub use details::{convert_with_confidence, LichTaDetails};
+pub use direction::{travel_directions, Direction, TravelDirections};
+pub use element::{element_relation, Element, ElementRelation};
+pub use error::LichTaError;
+pub use ext::DateLichTaExt;
+pub use flying_star::annual_flying_star;
+pub use format::IsoLike;
+pub use hoang_dao::{auspicious_hour_ranges, auspicious_hours};
+pub use holiday::{lunar_holiday, thanh_minh, Holiday};
+#[cfg(feature = "alloc")]
+pub use lunar_month::lunar_month_dates;
+pub use lunar_month::{lunar_month_iter, same_lunar_month_dates, LunarMonth, LunarMonthDays};
+#[cfg(feature = "alloc")]
+pub use lunar_year::short_months;
+pub use lunar_year::{has_double_spring, lunar_month_count, LunarYear};
+pub use mansion::{lunar_mansion, Mansion, MANSION_NAMES};
+pub use meridian::ObservationMeridian;
+#[cfg(feature = "alloc")]
+pub use month_grid::render_month_ascii;
+#[cfg(feature = "alloc")]
+pub use month_name::MonthLabelStyle;
+pub use month_name::{LunarMonthName, MONTH_NAMES};
+pub use moon::{days_to_next_full_moon, days_to_next_new_moon, surrounding_new_moons};
+#[cfg(feature = "alloc")]
+pub use named::NgayTaNamed;
 pub use ngay_ta::NgayTa;
+pub use season::Season;
+pub use sexagenary::SexagenaryDay;
+pub use solar_term::{
+    days_into_solar_term, days_until_next_solar_term, governing_trung_khi, solar_term,
+    solar_term_instant, solar_term_starting, trung_khi_of_month, SolarTerm, SOLAR_TERM_NAMES,
+};
+#[cfg(feature = "solar-time")]
+pub use solar_time::SolarTimeModel;
+#[cfg(feature = "alloc")]
+pub use tet::tet_dates;
+pub use tet::{days_until_tet, tet_date};
 pub use time::Date;
-pub use util::convert_date_to_lichta;
+pub use timezone::Timezone;
+pub use truc::{truc_of_day, Truc};
+pub use tuan::Tuan;
+pub use tuple::LichTaTuple;
+pub use util::{
+    bounding_month_11s, convert_date_to_lichta, convert_datetime_to_lichta, gregorian_of_lunar_day,
+    lunar_month_11_from, lunar_month_to_k, max_error_days, new_moon_day, supported_date_range,
+    try_convert_date_to_lichta, JulianMonthIndex,
+};
+#[cfg(feature = "alloc")]
+pub use year_calendar::{lunar_dates_for_weekday, year_calendar, CalendarDay, YearCalendar};
+pub use zodiac::Zodiac;
+pub use zoned::ZonedNgayTa;
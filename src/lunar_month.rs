@@ -0,0 +1,395 @@
+//! The [`LunarMonth`] struct and its associated `impl`s.
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use crate::util::{date_from_julian_day, get_lunar_month_span, get_month_start};
+use crate::{Date, LichTaError, NgayTa};
+
+/// A month of the LichTa calendar.
+///
+/// Iterating over a month yields each of its 29 or 30 days:
+///
+/// ```
+/// use lich_ta::{LunarMonth, Timezone};
+///
+/// let month = LunarMonth::try_new(4, 2024, false, Timezone::VIETNAM.hours()).unwrap();
+/// for ngay_ta in month {
+///     assert_eq!(ngay_ta.month(), 4);
+/// }
+/// assert_eq!(month.into_iter().count(), 29);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LunarMonth {
+    number: i32,
+    year: i32,
+    is_leap: bool,
+    length: i32,
+    start_date: Date,
+}
+
+impl LunarMonth {
+    pub(crate) fn from_parts(
+        number: i32,
+        year: i32,
+        is_leap: bool,
+        length: i32,
+        start_date: Date,
+    ) -> Self {
+        Self {
+            number,
+            year,
+            is_leap,
+            length,
+            start_date,
+        }
+    }
+
+    /// Get a month of the LichTa calendar.
+    ///
+    /// Returns an error if the month is not in `1..=12` or if the year has no such leap month.
+    pub fn try_new(
+        number: i32,
+        year: i32,
+        is_leap: bool,
+        timezone: f64,
+    ) -> Result<Self, LichTaError> {
+        let (month_start, length) = get_lunar_month_span(number, year, is_leap, timezone)?;
+        let start_date = date_from_julian_day(month_start).ok_or(LichTaError::DateOutOfRange)?;
+        Ok(Self::from_parts(number, year, is_leap, length, start_date))
+    }
+}
+
+impl LunarMonth {
+    /// Month number from 1 to 12, a leap month has the number of the month before it.
+    pub fn number(&self) -> i32 {
+        self.number
+    }
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn is_leap(&self) -> bool {
+        self.is_leap
+    }
+    /// Number of days in the month, 29 or 30.
+    pub fn length(&self) -> i32 {
+        self.length
+    }
+    /// Gregorian date of the first day of the month.
+    pub fn start_date(&self) -> Date {
+        self.start_date
+    }
+}
+
+impl LunarMonth {
+    /// Get the month following this one.
+    ///
+    /// A regular month is followed by its leap month when the year has one, and a leap month
+    /// by the regular month after it. Month 12 is followed by month 1 of the next year.
+    ///
+    /// Returns an error if the next month is outside the range supported by [`Date`].
+    pub fn next_month(&self, timezone: f64) -> Result<Self, LichTaError> {
+        if !self.is_leap {
+            if let Ok(leap_month) = Self::try_new(self.number, self.year, true, timezone) {
+                return Ok(leap_month);
+            }
+        }
+        if self.number == 12 {
+            Self::try_new(1, self.year + 1, false, timezone)
+        } else {
+            Self::try_new(self.number + 1, self.year, false, timezone)
+        }
+    }
+}
+
+/// Iterate over the lunar months from the one containing `start`, leap months included.
+///
+/// The months are computed as the iterator advances. It is empty if `start` is not in a real
+/// month, and ends at the last month supported by [`Date`].
+///
+/// Parameters:
+/// - `start`: Date in the first month.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn lunar_month_iter(start: NgayTa, timezone: f64) -> impl Iterator<Item = LunarMonth> {
+    let first = LunarMonth::try_new(start.month(), start.year(), start.is_leap_month(), timezone);
+    core::iter::successors(first.ok(), move |month| month.next_month(timezone).ok())
+}
+
+/// Check whether two Gregorian dates are in the same lunar month, leap flag included.
+///
+/// Same as comparing the converted dates with [`NgayTa::same_lunar_month`], but it only
+/// compares the new moons starting their months, without numbering them.
+///
+/// Parameters:
+/// - `a`, `b`: Gregorian dates.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn same_lunar_month_dates(a: Date, b: Date, timezone: f64) -> bool {
+    // A lunar month is never longer than 30 days
+    if (a - b).whole_days().abs() >= 30 {
+        return false;
+    }
+    get_month_start(a.to_julian_day(), timezone) == get_month_start(b.to_julian_day(), timezone)
+}
+
+/// Get the Gregorian dates of the days of a lunar month, without pairing them with a
+/// [`NgayTa`] like iterating over a [`LunarMonth`] does.
+///
+/// Parameters:
+/// - `month`: Lunar month from 1 to 12.
+/// - `year`: Lunar year.
+/// - `leap`: Whether the month is the leap month of the year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The 29 or 30 dates of the month, or `None` if the month does not exist in that
+/// year.
+#[cfg(feature = "alloc")]
+pub fn lunar_month_dates(
+    month: i32,
+    year: i32,
+    leap: bool,
+    timezone: f64,
+) -> Option<alloc::vec::Vec<Date>> {
+    let month = LunarMonth::try_new(month, year, leap, timezone).ok()?;
+    let start_date = month.start_date();
+    (0..month.length())
+        .map(|day| start_date.checked_add(time::Duration::days(day.into())))
+        .collect()
+}
+
+/// Months are ordered by year, then number, with a leap month right after the regular month
+/// of the same number.
+impl Ord for LunarMonth {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (
+            self.year,
+            self.number,
+            self.is_leap,
+            self.start_date,
+            self.length,
+        )
+            .cmp(&(
+                other.year,
+                other.number,
+                other.is_leap,
+                other.start_date,
+                other.length,
+            ))
+    }
+}
+
+impl PartialOrd for LunarMonth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Iterator over the days of a [`LunarMonth`].
+#[derive(Clone, Debug)]
+pub struct LunarMonthDays {
+    month: LunarMonth,
+    next_day: i32,
+}
+
+impl Iterator for LunarMonthDays {
+    type Item = NgayTa;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_day > self.month.length {
+            return None;
+        }
+        let ngay_ta = NgayTa::new(
+            self.next_day,
+            self.month.number,
+            self.month.year,
+            self.month.is_leap,
+        );
+        self.next_day += 1;
+        Some(ngay_ta)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.month.length - self.next_day + 1).max(0) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for LunarMonthDays {}
+
+impl FusedIterator for LunarMonthDays {}
+
+impl IntoIterator for LunarMonth {
+    type Item = NgayTa;
+    type IntoIter = LunarMonthDays;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LunarMonthDays {
+            month: self,
+            next_day: 1,
+        }
+    }
+}
+
+impl IntoIterator for &LunarMonth {
+    type Item = NgayTa;
+    type IntoIter = LunarMonthDays;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        let month = LunarMonth::try_new(6, 2025, true, 7.0).unwrap();
+        assert_eq!(
+            (month.number(), month.year(), month.is_leap()),
+            (6, 2025, true)
+        );
+        assert_eq!(month.length(), 29);
+        assert_eq!(
+            month.start_date(),
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+        assert_eq!(
+            LunarMonth::try_new(6, 2024, true, 7.0),
+            Err(LichTaError::NoSuchLeapMonth {
+                month: 6,
+                year: 2024
+            })
+        );
+    }
+
+    #[test]
+    fn test_days() {
+        let month = LunarMonth::try_new(5, 2024, false, 7.0).unwrap();
+        assert_eq!(month.length(), 30);
+        let mut days = month.into_iter();
+        assert_eq!(days.len(), 30);
+        let first = days.next().unwrap();
+        assert_eq!((first.day(), first.month(), first.year()), (1, 5, 2024));
+        let last = days.last().unwrap();
+        assert_eq!(last.day(), 30);
+
+        let month = LunarMonth::try_new(6, 2025, true, 7.0).unwrap();
+        assert_eq!((&month).into_iter().count(), 29);
+        assert!(month.into_iter().all(|ngay_ta| ngay_ta.is_leap_month()));
+    }
+
+    #[test]
+    fn test_next_month() {
+        // 2025 has a leap month 6
+        let expected = [
+            (2, false),
+            (3, false),
+            (4, false),
+            (5, false),
+            (6, false),
+            (6, true),
+            (7, false),
+            (8, false),
+            (9, false),
+            (10, false),
+            (11, false),
+            (12, false),
+        ];
+        let mut month = LunarMonth::try_new(1, 2025, false, 7.0).unwrap();
+        for (number, is_leap) in expected {
+            let next = month.next_month(7.0).unwrap();
+            assert_eq!(
+                (next.number(), next.year(), next.is_leap()),
+                (number, 2025, is_leap)
+            );
+            assert!(month < next);
+            assert_eq!(
+                next.start_date(),
+                month.start_date() + time::Duration::days(month.length().into())
+            );
+            month = next;
+        }
+        let next = month.next_month(7.0).unwrap();
+        assert_eq!(
+            (next.number(), next.year(), next.is_leap()),
+            (1, 2026, false)
+        );
+        assert!(month < next);
+    }
+
+    #[test]
+    fn test_lunar_month_iter() {
+        let start = NgayTa::new(10, 5, 2025, false);
+        let mut months = lunar_month_iter(start, 7.0);
+        for (number, is_leap) in [(5, false), (6, false), (6, true), (7, false)] {
+            let month = months.next().unwrap();
+            assert_eq!((month.number(), month.is_leap()), (number, is_leap));
+        }
+
+        let mut months = lunar_month_iter(NgayTa::new(1, 6, 2025, true), 7.0);
+        assert!(months.next().unwrap().is_leap());
+        assert_eq!(months.next().unwrap().number(), 7);
+
+        // 13 months from month 1 of 2025 to month 1 of 2026
+        let month = lunar_month_iter(NgayTa::new(1, 1, 2025, false), 7.0)
+            .nth(13)
+            .unwrap();
+        assert_eq!((month.number(), month.year()), (1, 2026));
+
+        assert_eq!(
+            lunar_month_iter(NgayTa::new(1, 5, 2025, true), 7.0).count(),
+            0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_lunar_month_dates() {
+        let dates = lunar_month_dates(6, 2025, true, 7.0).unwrap();
+        assert_eq!(dates.len(), 29);
+        assert_eq!(
+            dates[0],
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+        for (date, ngay_ta) in dates
+            .iter()
+            .zip(LunarMonth::try_new(6, 2025, true, 7.0).unwrap())
+        {
+            assert_eq!(NgayTa::from_date(*date, 7.0), ngay_ta);
+        }
+        assert_eq!(lunar_month_dates(7, 2025, false, 7.0).unwrap().len(), 30);
+
+        assert_eq!(lunar_month_dates(5, 2025, true, 7.0), None);
+        assert_eq!(lunar_month_dates(13, 2025, false, 7.0), None);
+    }
+
+    #[test]
+    fn test_same_lunar_month_dates() {
+        let date = |month, day| Date::from_calendar_date(2025, month, day).unwrap();
+        // Month 6 ends on July 24, the leap month 6 starts on July 25
+        let last_day = date(time::Month::July, 24);
+        let first_day = date(time::Month::July, 25);
+        assert!(same_lunar_month_dates(
+            date(time::Month::June, 25),
+            last_day,
+            7.0
+        ));
+        assert!(!same_lunar_month_dates(last_day, first_day, 7.0));
+        assert!(same_lunar_month_dates(
+            first_day,
+            date(time::Month::August, 22),
+            7.0
+        ));
+        assert!(same_lunar_month_dates(first_day, first_day, 7.0));
+
+        let mut a = date(time::Month::January, 1);
+        while a.year() == 2025 {
+            let b = a + time::Duration::days(17);
+            let expected = NgayTa::from_date(a, 7.0).same_lunar_month(&NgayTa::from_date(b, 7.0));
+            assert_eq!(same_lunar_month_dates(a, b, 7.0), expected, "{a} {b}");
+            assert_eq!(same_lunar_month_dates(b, a, 7.0), expected, "{b} {a}");
+            a = a.next_day().unwrap();
+        }
+    }
+}
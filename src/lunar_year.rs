@@ -0,0 +1,334 @@
+//! The [`LunarYear`] struct and its associated `impl`s.
+
+use core::cell::OnceCell;
+
+use crate::solar_term::solar_term_instant;
+use crate::util::{
+    convert_date_to_lichta, date_from_julian_day, get_lunar_month_index, get_new_moon_day,
+};
+use crate::{Date, LunarMonth, SolarTerm};
+
+/// Maximum number of months in a lunar year.
+const MAX_MONTHS: usize = 13;
+
+/// The months of a lunar year, in order.
+#[derive(Clone, Debug)]
+struct MonthTable {
+    months: [Option<LunarMonth>; MAX_MONTHS],
+}
+
+impl MonthTable {
+    /// Walk the new moons from this year's Tết to the next one.
+    ///
+    /// Months are numbered in sequence from 1, the leap month found by the conversion keeps
+    /// the number of the month before it.
+    fn compute(year: i32, timezone: f64) -> Self {
+        let first_month =
+            get_lunar_month_index(1, year, false, timezone).expect("Invalid date for year");
+        let next_first_month =
+            get_lunar_month_index(1, year + 1, false, timezone).expect("Invalid date for year");
+        let month_count = (next_first_month.value() - first_month.value()) as usize;
+
+        let mut months = [None; MAX_MONTHS];
+        let mut month_start = get_new_moon_day(first_month, timezone);
+        let mut number = 0;
+        for (i, month) in months.iter_mut().take(month_count).enumerate() {
+            let next_month_start = get_new_moon_day(first_month.offset(i as i32 + 1), timezone);
+            let start_date = date_from_julian_day(month_start).expect("Invalid date for month");
+            let (_, _, _, lunar_leap) = convert_date_to_lichta(start_date, timezone);
+            let is_leap = lunar_leap == 1 && number > 0;
+            if !is_leap {
+                number += 1;
+            }
+            let length = (next_month_start - month_start) as i32;
+            *month = Some(LunarMonth::from_parts(
+                number, year, is_leap, length, start_date,
+            ));
+            month_start = next_month_start;
+        }
+        Self { months }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &LunarMonth> {
+        self.months.iter().flatten()
+    }
+}
+
+/// A year of the LichTa calendar, from one Tết to the day before the next.
+///
+/// The months are computed on first use and kept for later calls.
+#[derive(Clone, Debug)]
+pub struct LunarYear {
+    year: i32,
+    timezone: f64,
+    months: OnceCell<MonthTable>,
+}
+
+impl LunarYear {
+    pub fn new(year: i32, timezone: f64) -> Self {
+        Self {
+            year,
+            timezone,
+            months: OnceCell::new(),
+        }
+    }
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn timezone(&self) -> f64 {
+        self.timezone
+    }
+}
+
+impl LunarYear {
+    fn month_table(&self) -> &MonthTable {
+        self.months
+            .get_or_init(|| MonthTable::compute(self.year, self.timezone))
+    }
+
+    /// Whether the year has 13 months.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+    pub fn is_leap(&self) -> bool {
+        self.month_table().iter().count() == MAX_MONTHS
+    }
+
+    /// Number of the leap month, `None` in a 12-month year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+    pub fn leap_month(&self) -> Option<i32> {
+        self.month_table()
+            .iter()
+            .find(|month| month.is_leap())
+            .map(LunarMonth::number)
+    }
+
+    /// Gregorian date of Tết, the first day of the year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+    pub fn tet(&self) -> Date {
+        self.month_table()
+            .iter()
+            .next()
+            .map(LunarMonth::start_date)
+            .expect("Invalid date for year")
+    }
+
+    /// Gregorian dates of the first and last days of the year: Tết and the day before the next
+    /// Tết, both included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+    pub fn gregorian_span(&self) -> (Date, Date) {
+        let last_month = self
+            .month_table()
+            .iter()
+            .last()
+            .expect("Invalid date for year");
+        let last_day = last_month.start_date() + time::Duration::days(last_month.length().into());
+        (
+            self.tet(),
+            last_day.previous_day().expect("Invalid date for year"),
+        )
+    }
+
+    /// The 12 or 13 months of the year, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+    #[cfg(feature = "alloc")]
+    pub fn months(&self) -> alloc::vec::Vec<LunarMonth> {
+        self.month_table().iter().copied().collect()
+    }
+}
+
+/// Get the number of months of a lunar year, 12 or 13.
+///
+/// The months are counted by new moons from the year's Tết to the next one, so the count
+/// agrees with [`LunarYear::is_leap`] without computing every month. The new moons between
+/// the month 11s bounding the year would not: that span runs from winter to winter, and holds
+/// the leap month 11 or 12 of the year before, e.g. the one of 2033, instead of its own.
+///
+/// Parameters:
+/// - `year`: Lunar year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+pub fn lunar_month_count(year: i32, timezone: f64) -> u8 {
+    let first_month =
+        get_lunar_month_index(1, year, false, timezone).expect("Invalid date for year");
+    let next_first_month =
+        get_lunar_month_index(1, year + 1, false, timezone).expect("Invalid date for year");
+    (next_first_month.value() - first_month.value()) as u8
+}
+
+/// Check whether a lunar year holds Lập Xuân twice (năm hai lần lập xuân), once after its
+/// Tết and once before the next one.
+///
+/// Lập Xuân returns every 365 days, so only a 13-month year, of 383 or 384 days, starting
+/// shortly before it can hold it twice.
+///
+/// Parameters:
+/// - `lunar_year`: Lunar year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+pub fn has_double_spring(lunar_year: i32, timezone: f64) -> bool {
+    let (first_day, last_day) = LunarYear::new(lunar_year, timezone).gregorian_span();
+    let lap_xuan = |year| solar_term_instant(year, SolarTerm::LapXuan, timezone).0;
+    lap_xuan(first_day.year()) >= first_day && lap_xuan(last_day.year()) <= last_day
+}
+
+/// Get the short months (tháng thiếu) of a lunar year, the months of 29 days, in order.
+///
+/// A leap month is listed with its number, like the regular month it follows, so a number
+/// appears twice when both are short. Short months can follow each other, like months 3 and 4
+/// of 2024.
+///
+/// Parameters:
+/// - `year`: Lunar year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if the year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+#[cfg(feature = "alloc")]
+pub fn short_months(year: i32, timezone: f64) -> alloc::vec::Vec<i32> {
+    LunarYear::new(year, timezone)
+        .month_table()
+        .iter()
+        .filter(|month| month.length() == 29)
+        .map(LunarMonth::number)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tet_date;
+
+    #[test]
+    fn test_leap_year() {
+        let lunar_year = LunarYear::new(2025, 7.0);
+        assert!(lunar_year.is_leap());
+        assert_eq!(lunar_year.leap_month(), Some(6));
+        assert_eq!(lunar_year.tet(), tet_date(2025, 7.0));
+
+        let lunar_year = LunarYear::new(2024, 7.0);
+        assert!(!lunar_year.is_leap());
+        assert_eq!(lunar_year.leap_month(), None);
+        assert_eq!(lunar_year.tet(), tet_date(2024, 7.0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_months() {
+        let months = LunarYear::new(2025, 7.0).months();
+        let numbers: alloc::vec::Vec<_> = months
+            .iter()
+            .map(|month| (month.number(), month.is_leap()))
+            .collect();
+        assert_eq!(
+            &numbers[4..8],
+            &[(5, false), (6, false), (6, true), (7, false)]
+        );
+        assert_eq!(
+            months[6].start_date(),
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+        assert_eq!(months[6].length(), 29);
+
+        let months = LunarYear::new(2024, 7.0).months();
+        assert_eq!(months.len(), 12);
+        assert!(months.iter().all(|month| !month.is_leap()));
+        let last = months[11];
+        assert_eq!(last.number(), 12);
+        assert_eq!(
+            last.start_date() + time::Duration::days(last.length().into()),
+            tet_date(2025, 7.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_short_months() {
+        // Months 3 and 4 of 2024 are both short
+        assert_eq!(short_months(2024, 7.0), [1, 3, 4, 6, 9, 12]);
+        // The 6 is the leap month 6, month 6 has 30 days
+        assert_eq!(short_months(2025, 7.0), [2, 4, 5, 6, 8, 12]);
+        for year in 2020..2030 {
+            let short = short_months(year, 7.0).len();
+            let long = LunarYear::new(year, 7.0)
+                .months()
+                .iter()
+                .filter(|month| month.length() == 30)
+                .count();
+            assert_eq!(short + long, usize::from(lunar_month_count(year, 7.0)));
+        }
+    }
+
+    #[test]
+    fn test_gregorian_span() {
+        let (first, last) = LunarYear::new(2024, 7.0).gregorian_span();
+        assert_eq!(first, tet_date(2024, 7.0));
+        assert_eq!(last.next_day().unwrap(), tet_date(2025, 7.0));
+        assert_eq!((last - first).whole_days() + 1, 354);
+
+        // The leap month 6 of 2025 adds 29 days
+        let (first, last) = LunarYear::new(2025, 7.0).gregorian_span();
+        assert_eq!(
+            first,
+            Date::from_calendar_date(2025, time::Month::January, 29).unwrap()
+        );
+        assert_eq!(
+            last,
+            Date::from_calendar_date(2026, time::Month::February, 16).unwrap()
+        );
+        assert_eq!((last - first).whole_days() + 1, 384);
+    }
+
+    #[test]
+    fn test_has_double_spring() {
+        for (year, expected) in [
+            (2020, true),
+            (2021, false),
+            (2022, false),
+            (2023, true),
+            (2024, false),
+            (2025, true),
+            (2026, false),
+        ] {
+            assert_eq!(has_double_spring(year, 7.0), expected, "{year}");
+            if expected {
+                assert!(LunarYear::new(year, 7.0).is_leap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_lunar_month_count() {
+        assert_eq!(lunar_month_count(2023, 7.0), 13);
+        assert_eq!(lunar_month_count(2024, 7.0), 12);
+        assert_eq!(lunar_month_count(2025, 7.0), 13);
+        for year in 2020..2030 {
+            let expected = if LunarYear::new(year, 7.0).is_leap() {
+                13
+            } else {
+                12
+            };
+            assert_eq!(lunar_month_count(year, 7.0), expected);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! The 28 lunar mansions (nhị thập bát tú) of the almanac.
+
+use crate::Date;
+
+/// Vietnamese names of the lunar mansions, in the order of [`Mansion::index`].
+pub const MANSION_NAMES: [&str; 28] = [
+    "Giác", "Cang", "Đê", "Phòng", "Tâm", "Vĩ", "Cơ", "Đẩu", "Ngưu", "Nữ", "Hư", "Nguy", "Thất",
+    "Bích", "Khuê", "Lâu", "Vị", "Mão", "Tất", "Chủy", "Sâm", "Tỉnh", "Quỷ", "Liễu", "Tinh",
+    "Trương", "Dực", "Chẩn",
+];
+
+/// Lunar mansion (tú), the star (sao) presiding over a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Mansion {
+    Giac,
+    Cang,
+    De,
+    Phong,
+    Tam,
+    /// Vĩ.
+    Vi,
+    Co,
+    Dau,
+    Nguu,
+    Nu,
+    Hu,
+    Nguy,
+    That,
+    Bich,
+    Khue,
+    Lau,
+    /// Vị.
+    Vy,
+    Mao,
+    Tat,
+    Chuy,
+    Sam,
+    /// Tỉnh, Tỉnh Mộc Hãn.
+    TinhMoc,
+    Quy,
+    Lieu,
+    /// Tinh, Tinh Nhật Mã.
+    TinhNhat,
+    Truong,
+    Duc,
+    Chan,
+}
+
+impl Mansion {
+    const ALL: [Mansion; 28] = [
+        Mansion::Giac,
+        Mansion::Cang,
+        Mansion::De,
+        Mansion::Phong,
+        Mansion::Tam,
+        Mansion::Vi,
+        Mansion::Co,
+        Mansion::Dau,
+        Mansion::Nguu,
+        Mansion::Nu,
+        Mansion::Hu,
+        Mansion::Nguy,
+        Mansion::That,
+        Mansion::Bich,
+        Mansion::Khue,
+        Mansion::Lau,
+        Mansion::Vy,
+        Mansion::Mao,
+        Mansion::Tat,
+        Mansion::Chuy,
+        Mansion::Sam,
+        Mansion::TinhMoc,
+        Mansion::Quy,
+        Mansion::Lieu,
+        Mansion::TinhNhat,
+        Mansion::Truong,
+        Mansion::Duc,
+        Mansion::Chan,
+    ];
+
+    /// Get the mansion at `index` in the cycle starting from Giác, wrapping around every 28.
+    pub fn from_index(index: i32) -> Self {
+        Self::ALL[index.rem_euclid(28) as usize]
+    }
+    /// Position in the cycle, from 0 (Giác) to 27 (Chẩn).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Vietnamese name, e.g. "Giác".
+    pub fn as_str(self) -> &'static str {
+        MANSION_NAMES[self.index()]
+    }
+    /// Whether the mansion is a good star (cát tinh) in the almanac.
+    pub fn is_auspicious(self) -> bool {
+        matches!(
+            self,
+            Mansion::Giac
+                | Mansion::Phong
+                | Mansion::Vi
+                | Mansion::Co
+                | Mansion::Dau
+                | Mansion::That
+                | Mansion::Bich
+                | Mansion::Lau
+                | Mansion::Vy
+                | Mansion::Tat
+                | Mansion::Sam
+                | Mansion::TinhMoc
+                | Mansion::Truong
+                | Mansion::Chan
+        )
+    }
+}
+
+/// Get the lunar mansion of a day.
+///
+/// The mansions cycle every 28 days with the Julian day number, four weeks exactly, so a
+/// mansion always falls on the same weekday: Giác on Thursdays. Like [`day_can_chi`], it does
+/// not depend on the timezone nor on the lunar date.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+///
+/// [`day_can_chi`]: crate::day_can_chi
+pub fn lunar_mansion(date: Date) -> Mansion {
+    Mansion::from_index(date.to_julian_day() + 11)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lunar_mansion() {
+        let date = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+        assert_eq!(lunar_mansion(date), Mansion::Vy);
+        assert_eq!(lunar_mansion(date + time::Duration::days(28)), Mansion::Vy);
+        assert_eq!(lunar_mansion(date.next_day().unwrap()), Mansion::Mao);
+        // Giác always falls on a Thursday
+        for days in 0..28 {
+            let day = date + time::Duration::days(days);
+            if lunar_mansion(day) == Mansion::Giac {
+                assert_eq!(day.weekday(), time::Weekday::Thursday);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_auspicious() {
+        assert!(Mansion::Giac.is_auspicious());
+        assert!(!Mansion::Cang.is_auspicious());
+        assert_eq!(
+            (0..28)
+                .map(Mansion::from_index)
+                .filter(|m| m.is_auspicious())
+                .count(),
+            14
+        );
+        assert_eq!(Mansion::TinhNhat.as_str(), "Tinh");
+        assert_eq!(Mansion::TinhMoc.as_str(), "Tỉnh");
+    }
+
+    #[test]
+    fn test_name_table() {
+        assert_eq!(MANSION_NAMES.len(), 28);
+        for (i, name) in MANSION_NAMES.iter().enumerate() {
+            assert!(!name.is_empty());
+            assert!(!MANSION_NAMES[..i].contains(name), "{name} is repeated");
+        }
+        for (i, mansion) in Mansion::ALL.iter().enumerate() {
+            assert_eq!(mansion.index(), i);
+        }
+        assert_eq!(Mansion::Giac.as_str(), "Giác");
+        assert_eq!(Mansion::Chan.as_str(), "Chẩn");
+    }
+}
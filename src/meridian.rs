@@ -0,0 +1,61 @@
+//! The [`ObservationMeridian`] presets of the national lunar calendars.
+
+use crate::{Converter, Timezone};
+
+/// Reference meridian of a national variant of the lunar calendar.
+///
+/// The variants share the same rules but observe new moons and solar terms at their own
+/// meridian, so a new moon close to midnight can start a month one day apart:
+///
+/// - Vietnam uses UTC+7 (105°E), since 1968.
+/// - China uses UTC+8 (120°E).
+/// - Korea uses UTC+9 (135°E). Some historical almanacs use UTC+8.5 (127.5°E) instead, pass
+///   `8.5` as the timezone directly to reproduce them.
+/// - Japan uses UTC+9 (135°E).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ObservationMeridian {
+    Vietnam,
+    China,
+    Korea,
+    Japan,
+}
+
+impl ObservationMeridian {
+    /// Timezone offset from UTC in hours of the meridian.
+    pub fn timezone(self) -> f64 {
+        match self {
+            ObservationMeridian::Vietnam => Timezone::VIETNAM,
+            ObservationMeridian::China => Timezone::CHINA,
+            ObservationMeridian::Korea => Timezone::KOREA,
+            ObservationMeridian::Japan => Timezone::JAPAN,
+        }
+        .hours()
+    }
+}
+
+impl Converter {
+    /// Create a converter for a national variant of the lunar calendar.
+    pub fn for_meridian(meridian: ObservationMeridian) -> Self {
+        Self::new(meridian.timezone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+
+    #[test]
+    fn test_vietnam_and_korea_differ() {
+        // The new moon of 2023-05-19 15:53 UTC falls before midnight in Vietnam only
+        let date = Date::from_calendar_date(2023, time::Month::May, 19).unwrap();
+        let vietnam = Converter::for_meridian(ObservationMeridian::Vietnam).convert(date);
+        let korea = Converter::for_meridian(ObservationMeridian::Korea).convert(date);
+        assert_eq!((vietnam.day(), vietnam.month()), (1, 4));
+        assert_eq!((korea.day(), korea.month()), (30, 3));
+
+        let next_day = date.next_day().unwrap();
+        let korea = Converter::for_meridian(ObservationMeridian::Korea).convert(next_day);
+        assert_eq!((korea.day(), korea.month()), (1, 4));
+    }
+}
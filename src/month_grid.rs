@@ -0,0 +1,121 @@
+//! Text rendering of a Gregorian month with its lunar days, ready to print.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::holiday::lunar_holiday;
+use crate::{Converter, Date, NgayTa};
+
+/// Weekday headers, the week starting on Monday (thứ Hai).
+const WEEKDAYS: [&str; 7] = ["T2", "T3", "T4", "T5", "T6", "T7", "CN"];
+/// Width of a day cell: the Gregorian day, a space and up to 5 characters of lunar day.
+const CELL_WIDTH: usize = 8;
+
+/// Write the lunar part of a day cell: the day number, "day/month" on the first day of a
+/// month with a `+` for a leap month, and a `*` on a holiday.
+fn push_lunar_day(cell: &mut String, ngay_ta: &NgayTa) {
+    // Writing to a String does not fail
+    let _ = write!(cell, "{}", ngay_ta.day());
+    if ngay_ta.day() == 1 {
+        let _ = write!(cell, "/{}", ngay_ta.month());
+        if ngay_ta.is_leap_month() {
+            cell.push('+');
+        }
+    }
+    if lunar_holiday(ngay_ta).is_some() {
+        cell.push('*');
+    }
+}
+
+/// Render a Gregorian month as a text calendar, one week per row from Monday to Sunday.
+///
+/// Each day shows its Gregorian day then its lunar day. The first day of a lunar month shows
+/// the month too, e.g. "1/6" or "1/6+" for a leap month, and holidays are marked with `*`.
+///
+/// ```text
+/// Tháng 1 năm 2025
+/// T2       T3       T4       T5       T6       T7       CN
+///                    1 2      2 3      3 4      4 5      5 6
+/// ...
+/// 27 28    28 29    29 1/1*  30 2     31 3
+/// ```
+///
+/// Parameters:
+/// - `year`: Gregorian year.
+/// - `month`: Gregorian month.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if the year is outside the range supported by [`Date`].
+pub fn render_month_ascii(year: i32, month: time::Month, timezone: f64) -> String {
+    let first_day = Date::from_calendar_date(year, month, 1).expect("Invalid date for year");
+    let mut converter = Converter::new(timezone);
+    let mut output = String::new();
+    let _ = writeln!(output, "Tháng {} năm {year}", month as u8);
+
+    let mut line = String::new();
+    for weekday in WEEKDAYS {
+        let _ = write!(line, "{weekday:<CELL_WIDTH$} ");
+    }
+    output.push_str(line.trim_end());
+    output.push('\n');
+
+    line.clear();
+    let leading_days = usize::from(first_day.weekday().number_days_from_monday());
+    line.extend(core::iter::repeat_n(' ', leading_days * (CELL_WIDTH + 1)));
+    let mut date = first_day;
+    loop {
+        let mut cell = String::new();
+        push_lunar_day(&mut cell, &converter.convert(date));
+        let _ = write!(
+            line,
+            "{:>2} {cell:<width$} ",
+            date.day(),
+            width = CELL_WIDTH - 3
+        );
+        let next_day = date.next_day().filter(|next| next.month() == month);
+        if date.weekday() == time::Weekday::Sunday || next_day.is_none() {
+            output.push_str(line.trim_end());
+            output.push('\n');
+            line.clear();
+        }
+        match next_day {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_render_month_ascii() {
+        // Starts on a Wednesday, Ông Công Ông Táo on the 22nd and Tết on the 29th
+        assert_eq!(
+            render_month_ascii(2025, Month::January, 7.0),
+            "Tháng 1 năm 2025
+T2       T3       T4       T5       T6       T7       CN
+                   1 2      2 3      3 4      4 5      5 6
+ 6 7      7 8      8 9      9 10    10 11    11 12    12 13
+13 14    14 15    15 16    16 17    17 18    18 19    19 20
+20 21    21 22    22 23*   23 24    24 25    25 26    26 27
+27 28    28 29    29 1/1*  30 2     31 3
+"
+        );
+        // The leap month 6 starts on a Friday
+        let calendar = render_month_ascii(2025, Month::July, 7.0);
+        assert!(calendar.contains("\n21 27    22 28    23 29    24 30    25 1/6+  26 2     27 3\n"));
+        // Starts on a Sunday, the last week has a single day
+        let calendar = render_month_ascii(2024, Month::September, 7.0);
+        let lines: alloc::vec::Vec<&str> = calendar.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[2].trim_start(), "1 29");
+        assert_eq!(lines[2].len(), 6 * 9 + 5);
+        assert_eq!(lines[7], "30 28");
+    }
+}
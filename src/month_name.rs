@@ -0,0 +1,201 @@
+//! The [`LunarMonthName`] enum and the month name of a [`NgayTa`].
+
+use crate::{LichTaError, NgayTa};
+
+/// Vietnamese names of the lunar months, `MONTH_NAMES[0]` being month 1 (Giêng).
+pub const MONTH_NAMES: [&str; 12] = [
+    "Giêng",
+    "Hai",
+    "Ba",
+    "Tư",
+    "Năm",
+    "Sáu",
+    "Bảy",
+    "Tám",
+    "Chín",
+    "Mười",
+    "Mười Một",
+    "Chạp",
+];
+
+/// Traditional name of a lunar month (tháng), from Giêng to Chạp.
+///
+/// A leap month has the name of the month it repeats, see [`NgayTa::is_leap_month`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LunarMonthName {
+    Gieng,
+    Hai,
+    Ba,
+    Tu,
+    Nam,
+    Sau,
+    Bay,
+    Tam,
+    Chin,
+    Muoi,
+    MuoiMot,
+    Chap,
+}
+
+impl LunarMonthName {
+    const ALL: [LunarMonthName; 12] = [
+        LunarMonthName::Gieng,
+        LunarMonthName::Hai,
+        LunarMonthName::Ba,
+        LunarMonthName::Tu,
+        LunarMonthName::Nam,
+        LunarMonthName::Sau,
+        LunarMonthName::Bay,
+        LunarMonthName::Tam,
+        LunarMonthName::Chin,
+        LunarMonthName::Muoi,
+        LunarMonthName::MuoiMot,
+        LunarMonthName::Chap,
+    ];
+
+    /// Vietnamese name, e.g. "Giêng".
+    pub fn as_str(self) -> &'static str {
+        MONTH_NAMES[self as usize]
+    }
+}
+
+/// Month number, from 1 (Giêng) to 12 (Chạp).
+impl From<LunarMonthName> for i32 {
+    fn from(name: LunarMonthName) -> Self {
+        name as i32 + 1
+    }
+}
+
+impl TryFrom<i32> for LunarMonthName {
+    type Error = LichTaError;
+
+    /// Returns an error if the month is not in `1..=12`.
+    fn try_from(month: i32) -> Result<Self, Self::Error> {
+        if !(1..=12).contains(&month) {
+            return Err(LichTaError::InvalidMonth(month));
+        }
+        Ok(Self::ALL[(month - 1) as usize])
+    }
+}
+
+impl NgayTa {
+    /// Get the name of the lunar month, e.g. [`LunarMonthName::Tu`] for month 4.
+    ///
+    /// The month is brought back into `1..=12` for values built with [`NgayTa::new`].
+    pub fn month_enum(&self) -> LunarMonthName {
+        LunarMonthName::ALL[(self.month() - 1).rem_euclid(12) as usize]
+    }
+}
+
+/// Way [`NgayTa::month_label`] writes a month, the leap month shown for month 4.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MonthLabelStyle {
+    /// "tháng Tư (nhuận)".
+    Vietnamese,
+    /// "tháng 4 (nhuận)".
+    Numeric,
+    /// "tháng 4+", like the `Display` form of [`NgayTa`].
+    NumericPlus,
+}
+
+#[cfg(feature = "alloc")]
+impl NgayTa {
+    /// Write the month of the date, e.g. "tháng Tư", marking a leap month in the given style.
+    pub fn month_label(&self, style: MonthLabelStyle) -> alloc::string::String {
+        use alloc::format;
+
+        let leap = self.is_leap_month();
+        match style {
+            MonthLabelStyle::Vietnamese if leap => {
+                format!("tháng {} (nhuận)", self.month_enum().as_str())
+            }
+            MonthLabelStyle::Vietnamese => format!("tháng {}", self.month_enum().as_str()),
+            MonthLabelStyle::Numeric if leap => format!("tháng {} (nhuận)", self.month()),
+            MonthLabelStyle::NumericPlus if leap => format!("tháng {}+", self.month()),
+            MonthLabelStyle::Numeric | MonthLabelStyle::NumericPlus => {
+                format!("tháng {}", self.month())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_enum() {
+        assert_eq!(
+            NgayTa::new(1, 1, 2025, false).month_enum(),
+            LunarMonthName::Gieng
+        );
+        assert_eq!(
+            NgayTa::new(1, 6, 2025, true).month_enum(),
+            LunarMonthName::Sau
+        );
+        assert_eq!(
+            NgayTa::new(23, 12, 2025, false).month_enum(),
+            LunarMonthName::Chap
+        );
+        assert_eq!(LunarMonthName::Tu.as_str(), "Tư");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_month_label() {
+        let leap = NgayTa::new(1, 4, 2020, true);
+        assert_eq!(
+            leap.month_label(MonthLabelStyle::Vietnamese),
+            "tháng Tư (nhuận)"
+        );
+        assert_eq!(
+            leap.month_label(MonthLabelStyle::Numeric),
+            "tháng 4 (nhuận)"
+        );
+        assert_eq!(leap.month_label(MonthLabelStyle::NumericPlus), "tháng 4+");
+
+        let regular = NgayTa::new(1, 11, 2024, false);
+        assert_eq!(
+            regular.month_label(MonthLabelStyle::Vietnamese),
+            "tháng Mười Một"
+        );
+        assert_eq!(regular.month_label(MonthLabelStyle::Numeric), "tháng 11");
+        assert_eq!(
+            regular.month_label(MonthLabelStyle::NumericPlus),
+            "tháng 11"
+        );
+    }
+
+    #[test]
+    fn test_month_number() {
+        for month in 1..=12 {
+            let name = LunarMonthName::try_from(month).unwrap();
+            assert_eq!(i32::from(name), month);
+        }
+        assert_eq!(
+            LunarMonthName::try_from(0),
+            Err(LichTaError::InvalidMonth(0))
+        );
+        assert_eq!(
+            LunarMonthName::try_from(13),
+            Err(LichTaError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_name_table() {
+        assert_eq!(LunarMonthName::ALL.len(), 12);
+        for (i, name) in LunarMonthName::ALL.iter().enumerate() {
+            assert_eq!(i32::from(*name), i as i32 + 1);
+            assert!(!name.as_str().is_empty());
+            assert!(
+                !LunarMonthName::ALL[..i]
+                    .iter()
+                    .any(|other| other.as_str() == name.as_str()),
+                "{} is repeated",
+                name.as_str()
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! New moons (mùng 1) and full moons (rằm) of the lunar months around a date.
+
+use crate::util::get_month_start;
+use crate::Date;
+
+/// Get the number of days from `date` to the next first day of a lunar month, the day of the
+/// new moon, 0 if `date` is one.
+///
+/// Parameters:
+/// - `date`: Gregorian date to count from.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn days_to_next_new_moon(date: Date, timezone: f64) -> u16 {
+    let julian_day = date.to_julian_day();
+    let month_start = get_month_start(julian_day, timezone);
+    if month_start == julian_day {
+        return 0;
+    }
+    // A month has at most 30 days, 30 days after its start is always in the next month
+    let next_month_start = get_month_start(month_start + 30, timezone);
+    (next_month_start - julian_day) as u16
+}
+
+/// Get the days of the new moons starting the lunar month of `date` and the next month.
+///
+/// The month of `date` runs from the first date to the day before the second one, `date`
+/// itself being the first on the day of a new moon.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if a new moon is outside the range supported by [`Date`].
+pub fn surrounding_new_moons(date: Date, timezone: f64) -> (Date, Date) {
+    let month_start = get_month_start(date.to_julian_day(), timezone);
+    let next_month_start = get_month_start(month_start + 30, timezone);
+    let to_date =
+        |julian_day| Date::from_julian_day(julian_day).expect("Invalid date for month start");
+    (to_date(month_start), to_date(next_month_start))
+}
+
+/// Get the number of days from `date` to the next 15th day of a lunar month, the traditional
+/// full moon (rằm), 0 if `date` is one.
+///
+/// The astronomical full moon can fall a day or two later than the 15th, the calendar day is
+/// the one observed.
+///
+/// Parameters:
+/// - `date`: Gregorian date to count from.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn days_to_next_full_moon(date: Date, timezone: f64) -> u16 {
+    let julian_day = date.to_julian_day();
+    let month_start = get_month_start(julian_day, timezone);
+    let full_moon = if julian_day <= month_start + 14 {
+        month_start + 14
+    } else {
+        get_month_start(month_start + 30, timezone) + 14
+    };
+    (full_moon - julian_day) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_days_to_next_new_moon() {
+        // Month 4 of 2024 began on 2024-05-08, month 5 on 2024-06-06
+        let month_start = Date::from_calendar_date(2024, Month::May, 8).unwrap();
+        assert_eq!(days_to_next_new_moon(month_start, 7.0), 0);
+        assert_eq!(
+            days_to_next_new_moon(month_start.next_day().unwrap(), 7.0),
+            28
+        );
+        let date = Date::from_calendar_date(2024, Month::June, 5).unwrap();
+        assert_eq!(days_to_next_new_moon(date, 7.0), 1);
+    }
+
+    #[test]
+    fn test_surrounding_new_moons() {
+        // Month 4 of 2024 began on 2024-05-08, month 5 on 2024-06-06
+        let month_start = Date::from_calendar_date(2024, Month::May, 8).unwrap();
+        let next_month_start = Date::from_calendar_date(2024, Month::June, 6).unwrap();
+        let expected = (month_start, next_month_start);
+        assert_eq!(surrounding_new_moons(month_start, 7.0), expected);
+        let date = Date::from_calendar_date(2024, Month::May, 24).unwrap();
+        assert_eq!(surrounding_new_moons(date, 7.0), expected);
+        let last_day = next_month_start.previous_day().unwrap();
+        assert_eq!(surrounding_new_moons(last_day, 7.0), expected);
+        assert_eq!(
+            surrounding_new_moons(next_month_start, 7.0).0,
+            next_month_start
+        );
+    }
+
+    #[test]
+    fn test_days_to_next_full_moon() {
+        // Rằm of month 4 of 2024 was on 2024-05-22, the one of month 5 on 2024-06-20
+        let month_start = Date::from_calendar_date(2024, Month::May, 8).unwrap();
+        assert_eq!(days_to_next_full_moon(month_start, 7.0), 14);
+        let full_moon = Date::from_calendar_date(2024, Month::May, 22).unwrap();
+        assert_eq!(days_to_next_full_moon(full_moon, 7.0), 0);
+        assert_eq!(
+            days_to_next_full_moon(full_moon.next_day().unwrap(), 7.0),
+            28
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! The [`NgayTaNamed`] struct, a [`NgayTa`] with all its names resolved.
+
+use alloc::string::String;
+
+use crate::{
+    day_can_chi, year_can_chi, Can, Chi, Date, LichTaError, NgayTa, SexagenaryDay, Zodiac,
+};
+
+/// Write a Can Chi pair as it is usually read, e.g. "Giáp Thìn".
+fn can_chi_name((can, chi): (Can, Chi)) -> String {
+    let mut name = String::from(can.as_str());
+    name.push(' ');
+    name.push_str(chi.as_str());
+    name
+}
+
+/// Names of a [`NgayTa`], ready to be displayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NgayTaNamed {
+    year_can_chi: String,
+    month_name: &'static str,
+    day_can_chi: Option<String>,
+    zodiac: Zodiac,
+    is_leap_month: bool,
+    season: &'static str,
+}
+
+impl NgayTaNamed {
+    /// Resolve the names of the lunar date of `date`, the day Can Chi included.
+    ///
+    /// Parameters:
+    /// - `date`: Gregorian date.
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    pub fn from_date(date: Date, timezone: f64) -> Self {
+        let mut named = Self::from(NgayTa::from_date(date, timezone));
+        named.day_can_chi = Some(can_chi_name(day_can_chi(date)));
+        named
+    }
+}
+
+impl NgayTaNamed {
+    /// Can Chi of the year, e.g. "Giáp Thìn".
+    pub fn year_can_chi(&self) -> &str {
+        &self.year_can_chi
+    }
+    /// Name of the month, e.g. "Giêng". A leap month has the name of the month it repeats.
+    pub fn month_name(&self) -> &'static str {
+        self.month_name
+    }
+    /// Can Chi of the day, e.g. "Giáp Thìn".
+    ///
+    /// The day cycle follows the Gregorian calendar, so it is only known when built with
+    /// [`NgayTaNamed::from_date`].
+    pub fn day_can_chi(&self) -> Option<&str> {
+        self.day_can_chi.as_deref()
+    }
+    /// Animal of the year.
+    pub fn zodiac(&self) -> Zodiac {
+        self.zodiac
+    }
+    pub fn is_leap_month(&self) -> bool {
+        self.is_leap_month
+    }
+    /// Season of the month, e.g. "Xuân".
+    pub fn season(&self) -> &'static str {
+        self.season
+    }
+}
+
+impl NgayTa {
+    /// Get the Can Chi name of the day, e.g. "Giáp Thìn".
+    ///
+    /// Parameters:
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    ///
+    /// Returns the same errors as [`NgayTa::to_date`].
+    pub fn sexagenary_day_name(&self, timezone: f64) -> Result<String, LichTaError> {
+        Ok(SexagenaryDay::from_date(self.to_date(timezone)?).name())
+    }
+}
+
+impl From<NgayTa> for NgayTaNamed {
+    fn from(ngay_ta: NgayTa) -> Self {
+        let year_can_chi = year_can_chi(ngay_ta.year());
+        Self {
+            year_can_chi: can_chi_name(year_can_chi),
+            month_name: ngay_ta.month_enum().as_str(),
+            day_can_chi: None,
+            zodiac: year_can_chi.1.zodiac(),
+            is_leap_month: ngay_ta.is_leap_month(),
+            season: ngay_ta.season().as_str(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ngay_ta() {
+        let named = NgayTaNamed::from(NgayTa::new(1, 6, 2025, true));
+        assert_eq!(named.year_can_chi(), "Ất Tỵ");
+        assert_eq!(named.month_name(), "Sáu");
+        assert_eq!(named.day_can_chi(), None);
+        assert_eq!(named.zodiac(), Zodiac::Ran);
+        assert!(named.is_leap_month());
+        assert_eq!(named.season(), "Hạ");
+    }
+
+    #[test]
+    fn test_from_date() {
+        // Tết Giáp Thìn was a Giáp Thìn day
+        let date = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+        let named = NgayTaNamed::from_date(date, 7.0);
+        assert_eq!(named.year_can_chi(), "Giáp Thìn");
+        assert_eq!(named.month_name(), "Giêng");
+        assert_eq!(named.day_can_chi(), Some("Giáp Thìn"));
+        assert_eq!(named.zodiac(), Zodiac::Rong);
+        assert!(!named.is_leap_month());
+        assert_eq!(named.season(), "Xuân");
+    }
+
+    #[test]
+    fn test_sexagenary_day_name() {
+        let tet = NgayTa::new(1, 1, 2024, false);
+        assert_eq!(tet.sexagenary_day_name(7.0).as_deref(), Ok("Giáp Thìn"));
+        assert_eq!(
+            NgayTa::new(2, 1, 2024, false)
+                .sexagenary_day_name(7.0)
+                .as_deref(),
+            Ok("Ất Tỵ")
+        );
+        assert!(NgayTa::new(30, 4, 2024, false)
+            .sexagenary_day_name(7.0)
+            .is_err());
+    }
+}
@@ -1,10 +1,18 @@
 //! The [`LichTa`] struct and its associated `impl`s.
 
-use crate::util::convert_date_to_lichta;
-use crate::Date;
+use crate::util::{
+    convert_date_to_lichta, get_lunar_month_index, get_lunar_month_span, gregorian_of_lunar_day,
+    try_convert_date_to_lichta,
+};
+use core::cmp::Ordering;
+
+use crate::{Date, LichTaError};
 
 /// NgayTa in the LichTa calendar.
-#[derive(Clone, Copy, Debug)]
+///
+/// Dates are ordered by year, month, then day, with a leap month right after the regular
+/// month of the same number: month 4 < leap month 4 < month 5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct NgayTa {
     day: i32,
     month: i32,
@@ -25,10 +33,73 @@ impl NgayTa {
     pub fn is_leap_month(&self) -> bool {
         self.is_leap_month
     }
+    /// Get a single integer identifying the month, leap flag included.
+    ///
+    /// The key is `month * 2 + 1` for a leap month and `month * 2` otherwise, so keys sort
+    /// like [`NgayTa`] does within a year, from 2 (month 1) to 25 (leap month 12).
+    pub fn month_key(&self) -> i32 {
+        self.month * 2 + i32::from(self.is_leap_month)
+    }
+    /// Get the month and leap flag back from a [`NgayTa::month_key`].
+    ///
+    /// Returns `None` if the key is not in `2..=25`.
+    pub fn month_from_key(key: i32) -> Option<(i32, bool)> {
+        (2..=25).contains(&key).then_some((key / 2, key % 2 == 1))
+    }
+    /// Check whether both dates are in the same lunar month, leap flag included.
+    pub fn same_lunar_month(&self, other: &Self) -> bool {
+        (self.year, self.month, self.is_leap_month)
+            == (other.year, other.month, other.is_leap_month)
+    }
+}
+
+/// Compare with the tuple form of [`convert_date_to_lichta`], `(day, month, year, leap)` where
+/// `leap` is 1 for a leap month and 0 otherwise.
+///
+/// [`convert_date_to_lichta`]: crate::convert_date_to_lichta
+impl PartialEq<(i32, i32, i32, i32)> for NgayTa {
+    fn eq(&self, other: &(i32, i32, i32, i32)) -> bool {
+        (
+            self.day,
+            self.month,
+            self.year,
+            i32::from(self.is_leap_month),
+        ) == *other
+    }
+}
+
+impl Ord for NgayTa {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.is_leap_month, self.day).cmp(&(
+            other.year,
+            other.month,
+            other.is_leap_month,
+            other.day,
+        ))
+    }
+}
+
+impl PartialOrd for NgayTa {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl NgayTa {
     pub fn new(day: i32, month: i32, year: i32, is_leap_month: bool) -> Self {
+        Self::new_unchecked(day, month, year, is_leap_month)
+    }
+    /// Create a [`NgayTa`] in a const context, without checking it.
+    ///
+    /// Use [`NgayTa::try_new`] or [`NgayTa::is_valid`] to check it at runtime.
+    ///
+    /// ```
+    /// use lich_ta::{NgayTa, Timezone};
+    ///
+    /// const TET: NgayTa = NgayTa::new_unchecked(1, 1, 2024, false);
+    /// assert!(TET.is_valid(Timezone::VIETNAM.hours()));
+    /// ```
+    pub const fn new_unchecked(day: i32, month: i32, year: i32, is_leap_month: bool) -> Self {
         Self {
             day,
             month,
@@ -36,8 +107,486 @@ impl NgayTa {
             is_leap_month,
         }
     }
+    /// Create a [`NgayTa`], checking that it is a real date of the LichTa calendar.
+    ///
+    /// Returns an error if the month is not in `1..=12`, if the year has no such leap month,
+    /// or if the day does not exist in the month.
+    pub fn try_new(
+        day: i32,
+        month: i32,
+        year: i32,
+        is_leap_month: bool,
+        timezone: f64,
+    ) -> Result<Self, LichTaError> {
+        let (_, month_length) = get_lunar_month_span(month, year, is_leap_month, timezone)?;
+        if !(1..=month_length).contains(&day) {
+            return Err(LichTaError::DayOutOfRange { day, month_length });
+        }
+        Ok(Self::new(day, month, year, is_leap_month))
+    }
     pub fn from_date(date: Date, timezone: f64) -> Self {
         let (day, month, year, is_leap_month) = convert_date_to_lichta(date, timezone);
         Self::new(day, month, year, is_leap_month == 1)
     }
+    /// Convert a Gregorian date, returning an error for a date outside
+    /// [`supported_date_range`](crate::supported_date_range) instead of panicking.
+    pub fn try_from_date(date: Date, timezone: f64) -> Result<Self, LichTaError> {
+        let (day, month, year, is_leap_month) = try_convert_date_to_lichta(date, timezone)?;
+        Ok(Self::new(day, month, year, is_leap_month == 1))
+    }
+    /// Convert a Gregorian date given as numbers, without going through [`time::Month`].
+    ///
+    /// Returns an error if the month is not in `1..=12`, if the day does not exist in the
+    /// Gregorian month, or if the date is outside
+    /// [`supported_date_range`](crate::supported_date_range).
+    pub fn from_ymd(year: i32, month: u8, day: u8, timezone: f64) -> Result<Self, LichTaError> {
+        let month =
+            time::Month::try_from(month).map_err(|_| LichTaError::InvalidMonth(month.into()))?;
+        let month_length = time::util::days_in_month(month, year);
+        if !(1..=month_length).contains(&day) {
+            return Err(LichTaError::DayOutOfRange {
+                day: day.into(),
+                month_length: month_length.into(),
+            });
+        }
+        let date =
+            Date::from_calendar_date(year, month, day).map_err(|_| LichTaError::DateOutOfRange)?;
+        Self::try_from_date(date, timezone)
+    }
+}
+
+/// Julian day number of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+/// The crate's epoch: 1/12/1899, the lunar month beginning with the new moon of the
+/// **Julian Month** 0 (1900-01-01, julian day 2415021, in UTC+7).
+impl Default for NgayTa {
+    fn default() -> Self {
+        Self::new(1, 12, 1899, false)
+    }
+}
+
+impl NgayTa {
+    /// Check that the fields correspond to a real date of the LichTa calendar.
+    ///
+    /// This is the boolean companion of [`NgayTa::try_new`], useful for values built with
+    /// [`NgayTa::new`] or read back from storage.
+    pub fn is_valid(&self, timezone: f64) -> bool {
+        Self::try_new(
+            self.day,
+            self.month,
+            self.year,
+            self.is_leap_month,
+            timezone,
+        )
+        .is_ok()
+    }
+    /// Convert back to the Gregorian calendar.
+    ///
+    /// Returns an error if the month is not in `1..=12`, if the year has no such leap month,
+    /// or if the day does not exist in the month, e.g. day 30 of a 29-day month, instead of
+    /// rolling over into the next month.
+    pub fn to_date(&self, timezone: f64) -> Result<Date, LichTaError> {
+        gregorian_of_lunar_day(
+            self.year,
+            self.month,
+            self.is_leap_month,
+            self.day,
+            timezone,
+        )
+    }
+    /// Get the number of days from 1970-01-01, the Unix epoch, to the Gregorian date.
+    ///
+    /// This is a single integer to store or compare, in the same order as [`Ord`] for dates
+    /// of the same timezone.
+    ///
+    /// Returns the same errors as [`NgayTa::to_date`].
+    pub fn to_epoch_days(&self, timezone: f64) -> Result<i64, LichTaError> {
+        let date = self.to_date(timezone)?;
+        Ok(i64::from(date.to_julian_day() - UNIX_EPOCH_JULIAN_DAY))
+    }
+    /// Convert a number of days from 1970-01-01, as given by [`NgayTa::to_epoch_days`].
+    ///
+    /// Returns an error if the date is outside
+    /// [`supported_date_range`](crate::supported_date_range).
+    pub fn from_epoch_days(days: i64, timezone: f64) -> Result<Self, LichTaError> {
+        let julian_day = days
+            .checked_add(UNIX_EPOCH_JULIAN_DAY.into())
+            .and_then(|julian_day| i32::try_from(julian_day).ok())
+            .ok_or(LichTaError::DateOutOfRange)?;
+        let date = Date::from_julian_day(julian_day).map_err(|_| LichTaError::DateOutOfRange)?;
+        Self::try_from_date(date, timezone)
+    }
+    /// Whether the lunar month of the date has 30 days, so that day 30 exists.
+    ///
+    /// Returns `false` for a 29-day month, and for a month that does not exist in the year.
+    pub fn month_has_30_days(&self, timezone: f64) -> bool {
+        get_lunar_month_span(self.month, self.year, self.is_leap_month, timezone)
+            .is_ok_and(|(_, month_length)| month_length == 30)
+    }
+    /// Reduce the day to the last day of the month when the month is shorter.
+    ///
+    /// This is the "last day of the month" policy for recurring events: day 30 becomes day 29
+    /// in a 29-day month. It is a no-op when the day already fits, or when the month does not
+    /// exist in the year.
+    pub fn clamp_to_month_end(&self, timezone: f64) -> NgayTa {
+        match get_lunar_month_span(self.month, self.year, self.is_leap_month, timezone) {
+            Ok((_, month_length)) if self.day > month_length => Self {
+                day: month_length,
+                ..*self
+            },
+            _ => *self,
+        }
+    }
+    /// Get the same day and month `n` lunar years later, e.g. the next death anniversary.
+    ///
+    /// The date is moved to a month that exists, the same way as `anniversaries_in_range`
+    /// does:
+    /// - A leap month falls on the regular month of the same number in a year without that
+    ///   leap month.
+    /// - Day 30 falls on day 29 in a month of 29 days, see [`NgayTa::clamp_to_month_end`].
+    ///
+    /// A negative `n` counts back. The fields are kept as they are when the target year is
+    /// outside the range supported by [`Date`].
+    pub fn add_lunar_years(&self, n: i32, timezone: f64) -> NgayTa {
+        let year = self.year.saturating_add(n);
+        let is_leap_month =
+            self.is_leap_month && get_lunar_month_span(self.month, year, true, timezone).is_ok();
+        Self::new(self.day, self.month, year, is_leap_month).clamp_to_month_end(timezone)
+    }
+    /// Get the Gregorian date of the first day of the lunar month, its new moon.
+    ///
+    /// Returns an error if the month is not in `1..=12` or if the year has no such leap month.
+    /// When converting from a Gregorian date, [`LichTaDetails::month_start`] gives the same
+    /// date without searching for the month again.
+    ///
+    /// [`LichTaDetails::month_start`]: crate::LichTaDetails::month_start
+    pub fn month_start_date(&self, timezone: f64) -> Result<Date, LichTaError> {
+        gregorian_of_lunar_day(self.year, self.month, self.is_leap_month, 1, timezone)
+    }
+    /// Get the position of the lunar month in its year, from 1 to 13, the leap month counting
+    /// as a month of its own.
+    ///
+    /// This is the month number up to the leap month and one more from it onward: in 2025,
+    /// with a leap month 6, the leap month is the 7th and month 7 is the 8th.
+    ///
+    /// Returns an error if the month is not in `1..=12` or if the year has no such leap month.
+    pub fn month_ordinal(&self, timezone: f64) -> Result<u8, LichTaError> {
+        let first_month = get_lunar_month_index(1, self.year, false, timezone)?;
+        let month = get_lunar_month_index(self.month, self.year, self.is_leap_month, timezone)?;
+        Ok((month.value() - first_month.value() + 1) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        assert!(NgayTa::try_new(29, 4, 2024, false, 7.0).is_ok());
+        assert_eq!(
+            NgayTa::try_new(30, 4, 2024, false, 7.0).unwrap_err(),
+            LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            }
+        );
+        assert_eq!(
+            NgayTa::try_new(1, 4, 2024, true, 7.0).unwrap_err(),
+            LichTaError::NoSuchLeapMonth {
+                month: 4,
+                year: 2024
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_ymd() {
+        let ngay_ta = NgayTa::from_ymd(2024, 5, 24, 7.0).unwrap();
+        assert_eq!(
+            (ngay_ta.day(), ngay_ta.month(), ngay_ta.year()),
+            (17, 4, 2024)
+        );
+        assert_eq!(
+            NgayTa::from_ymd(2024, 13, 1, 7.0).unwrap_err(),
+            LichTaError::InvalidMonth(13)
+        );
+        assert_eq!(
+            NgayTa::from_ymd(2023, 2, 29, 7.0).unwrap_err(),
+            LichTaError::DayOutOfRange {
+                day: 29,
+                month_length: 28
+            }
+        );
+        assert!(NgayTa::from_ymd(2024, 2, 29, 7.0).is_ok());
+        let beyond = crate::supported_date_range().end().year() + 1;
+        assert_eq!(
+            NgayTa::from_ymd(beyond, 1, 1, 7.0).unwrap_err(),
+            LichTaError::DateOutOfRange
+        );
+        assert_eq!(
+            NgayTa::from_ymd(Date::MAX.year(), 12, 31, 7.0).unwrap_err(),
+            LichTaError::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_epoch_days() {
+        let unix_epoch = NgayTa::from_date(Date::from_julian_day(2_440_588).unwrap(), 7.0);
+        assert_eq!(unix_epoch.to_epoch_days(7.0), Ok(0));
+        assert_eq!(NgayTa::from_epoch_days(0, 7.0), Ok(unix_epoch));
+        // Tết Giáp Thìn, 2024-02-10
+        let tet = NgayTa::new(1, 1, 2024, false);
+        assert_eq!(tet.to_epoch_days(7.0), Ok(19_763));
+        assert_eq!(
+            NgayTa::new(30, 4, 2024, false).to_epoch_days(7.0),
+            Err(LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+        assert_eq!(
+            NgayTa::from_epoch_days(i64::MAX, 7.0),
+            Err(LichTaError::DateOutOfRange)
+        );
+
+        // Through the leap month 6 of 2025 the days follow the order of the dates
+        let mut previous = NgayTa::from_epoch_days(20_200, 7.0).unwrap();
+        for days in 20_201..20_400 {
+            let ngay_ta = NgayTa::from_epoch_days(days, 7.0).unwrap();
+            assert!(previous < ngay_ta);
+            assert_eq!(ngay_ta.to_epoch_days(7.0), Ok(days));
+            previous = ngay_ta;
+        }
+    }
+
+    #[test]
+    fn test_month_has_30_days() {
+        // Month 4 of 2024 has 29 days, month 5 has 30
+        assert!(!NgayTa::new(1, 4, 2024, false).month_has_30_days(7.0));
+        assert!(NgayTa::new(1, 5, 2024, false).month_has_30_days(7.0));
+        // Month 7 of 2025 has 30 days, month 8 has 29
+        assert!(NgayTa::new(15, 7, 2025, false).month_has_30_days(7.0));
+        assert!(!NgayTa::new(15, 8, 2025, false).month_has_30_days(7.0));
+        assert!(!NgayTa::new(1, 5, 2025, true).month_has_30_days(7.0));
+    }
+
+    #[test]
+    fn test_month_ordinal() {
+        assert_eq!(NgayTa::new(1, 1, 2025, false).month_ordinal(7.0), Ok(1));
+        assert_eq!(NgayTa::new(1, 6, 2025, false).month_ordinal(7.0), Ok(6));
+        assert_eq!(NgayTa::new(1, 6, 2025, true).month_ordinal(7.0), Ok(7));
+        assert_eq!(NgayTa::new(1, 7, 2025, false).month_ordinal(7.0), Ok(8));
+        assert_eq!(NgayTa::new(29, 12, 2025, false).month_ordinal(7.0), Ok(13));
+        assert_eq!(NgayTa::new(1, 12, 2024, false).month_ordinal(7.0), Ok(12));
+        assert_eq!(
+            NgayTa::new(1, 5, 2025, true).month_ordinal(7.0),
+            Err(LichTaError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            })
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let epoch = NgayTa::default();
+        let date = Date::from_calendar_date(1900, time::Month::January, 1).unwrap();
+        let ngay_ta = NgayTa::from_date(date, 7.0);
+        assert_eq!(
+            (
+                epoch.day(),
+                epoch.month(),
+                epoch.year(),
+                epoch.is_leap_month()
+            ),
+            (
+                ngay_ta.day(),
+                ngay_ta.month(),
+                ngay_ta.year(),
+                ngay_ta.is_leap_month()
+            )
+        );
+        assert!(epoch.is_valid(7.0));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(NgayTa::new(17, 4, 2024, false).is_valid(7.0));
+        assert!(NgayTa::new(1, 6, 2025, true).is_valid(7.0));
+        assert!(!NgayTa::new(1, 5, 2025, true).is_valid(7.0));
+        assert!(!NgayTa::new(0, 4, 2024, false).is_valid(7.0));
+        assert!(!NgayTa::new(1, 0, 2024, false).is_valid(7.0));
+        let beyond = crate::supported_date_range().end().year() + 1;
+        assert!(!NgayTa::new(1, 1, beyond, false).is_valid(7.0));
+
+        let date = Date::from_calendar_date(2025, time::Month::August, 1).unwrap();
+        assert!(NgayTa::from_date(date, 7.0).is_valid(7.0));
+    }
+
+    #[test]
+    fn test_to_date() {
+        let ngay_ta = NgayTa::new(17, 4, 2024, false);
+        assert_eq!(
+            ngay_ta.to_date(7.0).unwrap(),
+            Date::from_calendar_date(2024, time::Month::May, 24).unwrap()
+        );
+        let ngay_ta = NgayTa::new(1, 6, 2025, true);
+        assert_eq!(
+            ngay_ta.to_date(7.0).unwrap(),
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+        assert_eq!(
+            NgayTa::new(1, 5, 2025, true).to_date(7.0).unwrap_err(),
+            LichTaError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_date_day_30() {
+        // 2025 has a leap month 6, so 13 months are checked
+        let months = (1..=12)
+            .map(|month| (month, false))
+            .chain(core::iter::once((6, true)));
+        let mut short_months = 0;
+        for (month, is_leap_month) in months {
+            let (_, month_length) = get_lunar_month_span(month, 2025, is_leap_month, 7.0).unwrap();
+            let last_day = NgayTa::new(29, month, 2025, is_leap_month);
+            let day_30 = NgayTa::new(30, month, 2025, is_leap_month);
+            let last = last_day.to_date(7.0).unwrap();
+            if month_length == 29 {
+                short_months += 1;
+                assert_eq!(
+                    day_30.to_date(7.0).unwrap_err(),
+                    LichTaError::DayOutOfRange {
+                        day: 30,
+                        month_length: 29
+                    }
+                );
+            } else {
+                assert_eq!(day_30.to_date(7.0).unwrap(), last.next_day().unwrap());
+            }
+            let back = NgayTa::from_date(last, 7.0);
+            assert_eq!(
+                (back.day(), back.month(), back.is_leap_month()),
+                (29, month, is_leap_month)
+            );
+        }
+        assert!(short_months > 0);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let month_4 = NgayTa::new(30, 4, 2025, false);
+        let leap_month_4 = NgayTa::new(1, 4, 2025, true);
+        let month_5 = NgayTa::new(1, 5, 2025, false);
+        assert!(month_4 < leap_month_4);
+        assert!(leap_month_4 < month_5);
+        assert!(NgayTa::new(29, 12, 2024, false) < NgayTa::new(1, 1, 2025, false));
+        assert_eq!(month_4.max(leap_month_4), leap_month_4);
+
+        assert!(month_4.same_lunar_month(&NgayTa::new(1, 4, 2025, false)));
+        assert!(!month_4.same_lunar_month(&leap_month_4));
+        assert!(!month_4.same_lunar_month(&NgayTa::new(30, 4, 2024, false)));
+    }
+
+    #[test]
+    fn test_ordering_follows_dates() {
+        // Every day of a leap year sorts after the day before it
+        let mut date = Date::from_calendar_date(2025, time::Month::January, 29).unwrap();
+        let mut previous = NgayTa::from_date(date, 7.0);
+        for _ in 0..383 {
+            date = date.next_day().unwrap();
+            let ngay_ta = NgayTa::from_date(date, 7.0);
+            assert!(previous < ngay_ta, "{previous:?} < {ngay_ta:?}");
+            previous = ngay_ta;
+        }
+    }
+
+    #[test]
+    fn test_month_key() {
+        assert_eq!(NgayTa::new(1, 1, 2025, false).month_key(), 2);
+        assert_eq!(NgayTa::new(1, 6, 2025, false).month_key(), 12);
+        assert_eq!(NgayTa::new(1, 6, 2025, true).month_key(), 13);
+        assert_eq!(NgayTa::new(1, 7, 2025, false).month_key(), 14);
+        for key in 2..=25 {
+            let (month, is_leap_month) = NgayTa::month_from_key(key).unwrap();
+            assert_eq!(NgayTa::new(1, month, 2025, is_leap_month).month_key(), key);
+        }
+        assert_eq!(NgayTa::month_from_key(1), None);
+        assert_eq!(NgayTa::month_from_key(26), None);
+    }
+
+    #[test]
+    fn test_month_start_date() {
+        let date = Date::from_calendar_date(2024, time::Month::May, 24).unwrap();
+        let ngay_ta = NgayTa::from_date(date, 7.0);
+        assert_eq!(
+            ngay_ta.month_start_date(7.0).unwrap(),
+            Date::from_calendar_date(2024, time::Month::May, 8).unwrap()
+        );
+        assert_eq!(
+            ngay_ta.month_start_date(7.0).unwrap(),
+            crate::LichTaDetails::from_date(date, 7.0).month_start()
+        );
+        assert_eq!(
+            NgayTa::new(30, 6, 2025, true)
+                .month_start_date(7.0)
+                .unwrap(),
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eq_tuple() {
+        let date = Date::from_calendar_date(2025, time::Month::August, 1).unwrap();
+        let ngay_ta = NgayTa::from_date(date, 7.0);
+        assert_eq!(ngay_ta, convert_date_to_lichta(date, 7.0));
+        assert_eq!(ngay_ta, (8, 6, 2025, 1));
+        assert_ne!(ngay_ta, (8, 6, 2025, 0));
+        assert_eq!(NgayTa::new(17, 4, 2024, false), (17, 4, 2024, 0));
+    }
+
+    #[test]
+    fn test_clamp_to_month_end() {
+        // Month 4 of 2024 has 29 days, month 5 has 30
+        let clamped = NgayTa::new(30, 4, 2024, false).clamp_to_month_end(7.0);
+        assert_eq!(clamped, NgayTa::new(29, 4, 2024, false));
+        assert!(clamped.is_valid(7.0));
+        let day_30 = NgayTa::new(30, 5, 2024, false);
+        assert_eq!(day_30.clamp_to_month_end(7.0), day_30);
+        let day_15 = NgayTa::new(15, 4, 2024, false);
+        assert_eq!(day_15.clamp_to_month_end(7.0), day_15);
+        let no_such_month = NgayTa::new(30, 4, 2024, true);
+        assert_eq!(no_such_month.clamp_to_month_end(7.0), no_such_month);
+    }
+
+    #[test]
+    fn test_add_lunar_years() {
+        let date = NgayTa::new(17, 4, 2024, false);
+        assert_eq!(
+            date.add_lunar_years(1, 7.0),
+            NgayTa::new(17, 4, 2025, false)
+        );
+        assert_eq!(
+            date.add_lunar_years(-1, 7.0),
+            NgayTa::new(17, 4, 2023, false)
+        );
+        assert_eq!(date.add_lunar_years(0, 7.0), date);
+        // 2026 has no leap month 6
+        let leap = NgayTa::new(1, 6, 2025, true);
+        assert_eq!(leap.add_lunar_years(0, 7.0), leap);
+        assert_eq!(leap.add_lunar_years(1, 7.0), NgayTa::new(1, 6, 2026, false));
+        // Month 5 of 2024 has 30 days, month 5 of 2025 has 29
+        let day_30 = NgayTa::new(30, 5, 2024, false);
+        assert_eq!(
+            day_30.add_lunar_years(1, 7.0),
+            NgayTa::new(29, 5, 2025, false)
+        );
+        assert!(day_30.add_lunar_years(1, 7.0).is_valid(7.0));
+    }
 }
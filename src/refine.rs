@@ -0,0 +1,162 @@
+//! Second pass on new moons close to local midnight, behind the `high-accuracy` feature.
+//!
+//! The AA98 new moon keeps only the largest periodic terms and is off by up to a few minutes.
+//! That only matters when the new moon is close to midnight, where it decides which day a
+//! month starts. For those new moons, the full series of Meeus' *Astronomical Algorithms*
+//! (chapter 49, with the planetary arguments) is evaluated instead.
+//!
+//! In UTC+7, this moves the first day of these months between 1900 and 2030, both matching
+//! the observed new moons:
+//!
+//! | AA98       | Refined    |
+//! |------------|------------|
+//! | 1944-06-21 | 1944-06-20 |
+//! | 1967-07-08 | 1967-07-07 |
+
+use crate::util::{delta_t_parabola, JulianMonthIndex, MIDNIGHT_WINDOW};
+
+/// Lunations between the AA98 epoch (new moon of 1900-01-01) and Meeus' (2000-01-06).
+const MEEUS_EPOCH_OFFSET: f64 = 1237.0;
+
+/// Periodic terms of the new moon: coefficient, power of E, multiples of M, M', F and Ω.
+const NEW_MOON_TERMS: [(f64, i32, [f64; 4]); 25] = [
+    (-0.40720, 0, [0.0, 1.0, 0.0, 0.0]),
+    (0.17241, 1, [1.0, 0.0, 0.0, 0.0]),
+    (0.01608, 0, [0.0, 2.0, 0.0, 0.0]),
+    (0.01039, 0, [0.0, 0.0, 2.0, 0.0]),
+    (0.00739, 1, [-1.0, 1.0, 0.0, 0.0]),
+    (-0.00514, 1, [1.0, 1.0, 0.0, 0.0]),
+    (0.00208, 2, [2.0, 0.0, 0.0, 0.0]),
+    (-0.00111, 0, [0.0, 1.0, -2.0, 0.0]),
+    (-0.00057, 0, [0.0, 1.0, 2.0, 0.0]),
+    (0.00056, 1, [1.0, 2.0, 0.0, 0.0]),
+    (-0.00042, 0, [0.0, 3.0, 0.0, 0.0]),
+    (0.00042, 1, [1.0, 0.0, 2.0, 0.0]),
+    (0.00038, 1, [1.0, 0.0, -2.0, 0.0]),
+    (-0.00024, 1, [-1.0, 2.0, 0.0, 0.0]),
+    (-0.00017, 0, [0.0, 0.0, 0.0, 1.0]),
+    (-0.00007, 0, [2.0, 1.0, 0.0, 0.0]),
+    (0.00004, 0, [0.0, 2.0, -2.0, 0.0]),
+    (0.00004, 0, [3.0, 0.0, 0.0, 0.0]),
+    (0.00003, 0, [1.0, 1.0, -2.0, 0.0]),
+    (0.00003, 0, [0.0, 2.0, 2.0, 0.0]),
+    (-0.00003, 0, [1.0, 1.0, 2.0, 0.0]),
+    (0.00003, 0, [-1.0, 1.0, 2.0, 0.0]),
+    (-0.00002, 0, [-1.0, 1.0, -2.0, 0.0]),
+    (-0.00002, 0, [1.0, 3.0, 0.0, 0.0]),
+    (0.00002, 0, [0.0, 4.0, 0.0, 0.0]),
+];
+
+/// Planetary arguments: coefficient, then the argument as constant and rate per lunation.
+const PLANETARY_TERMS: [(f64, f64, f64); 14] = [
+    (0.000325, 299.77, 0.107408),
+    (0.000165, 251.88, 0.016321),
+    (0.000164, 251.83, 26.651886),
+    (0.000126, 349.42, 36.412478),
+    (0.000110, 84.66, 18.206239),
+    (0.000062, 141.74, 53.303771),
+    (0.000060, 207.14, 2.453732),
+    (0.000056, 154.84, 7.306860),
+    (0.000047, 34.52, 27.261239),
+    (0.000042, 207.19, 0.121824),
+    (0.000040, 291.34, 1.844379),
+    (0.000037, 161.72, 24.198154),
+    (0.000035, 239.56, 25.513099),
+    (0.000023, 331.55, 3.592518),
+];
+
+/// Calculate the new moon in Terrestrial Time with the full series of Meeus.
+///
+/// Parameters:
+/// - `k`: number of lunations since the new moon of 2000-01-06.
+///
+/// Return: Julian ephemeris day
+fn new_moon_meeus(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let (t_2, t_3, t_4) = (t * t, t * t * t, t * t * t * t);
+    let mean_new_moon = 2451550.09766 + 29.530588861 * k + 0.00015437 * t_2 - 0.000000150 * t_3
+        + 0.00000000073 * t_4;
+    let eccentricity = 1.0 - 0.002516 * t - 0.0000074 * t_2;
+    let sun_mean_anomaly = 2.5534 + 29.10535670 * k - 0.0000014 * t_2 - 0.00000011 * t_3;
+    let moon_mean_anomaly =
+        201.5643 + 385.81693528 * k + 0.0107582 * t_2 + 0.00001238 * t_3 - 0.000000058 * t_4;
+    let moon_argument_latitude =
+        160.7108 + 390.67050284 * k - 0.0016118 * t_2 - 0.00000227 * t_3 + 0.000000011 * t_4;
+    let moon_ascending_node = 124.7746 - 1.56375588 * k + 0.0020672 * t_2 + 0.00000215 * t_3;
+
+    let arguments = [
+        sun_mean_anomaly,
+        moon_mean_anomaly,
+        moon_argument_latitude,
+        moon_ascending_node,
+    ];
+    let periodic: f64 = NEW_MOON_TERMS
+        .iter()
+        .map(|&(coefficient, e_power, multiples)| {
+            let angle: f64 = multiples.iter().zip(arguments).map(|(m, a)| m * a).sum();
+            coefficient * eccentricity.powi(e_power) * angle.to_radians().sin()
+        })
+        .sum();
+    let planetary: f64 = PLANETARY_TERMS
+        .iter()
+        .enumerate()
+        .map(|(i, &(coefficient, constant, rate))| {
+            // The first argument also has a quadratic term
+            let quadratic = if i == 0 { -0.009173 * t_2 } else { 0.0 };
+            coefficient * (constant + rate * k + quadratic).to_radians().sin()
+        })
+        .sum();
+    mean_new_moon + periodic + planetary
+}
+
+/// Refine an AA98 new moon when it is within [`MIDNIGHT_WINDOW`] of local midnight.
+///
+/// Parameters:
+/// - `julian_month_index`: number of **Julian Month** of the new moon.
+/// - `new_moon`: AA98 Julian day of the new moon.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Return: Julian day
+pub(crate) fn refine_new_moon(
+    julian_month_index: JulianMonthIndex,
+    new_moon: f64,
+    timezone: f64,
+) -> f64 {
+    let local = new_moon + 0.5 + timezone / 24.0;
+    let since_midnight = local - local.floor();
+    if MIDNIGHT_WINDOW < since_midnight && since_midnight < 1.0 - MIDNIGHT_WINDOW {
+        return new_moon;
+    }
+    let meeus = new_moon_meeus(f64::from(julian_month_index) - MEEUS_EPOCH_OFFSET);
+    let refined = meeus - delta_t_parabola(meeus);
+    // Millennia away from 2000 the ΔT of the two models drifts apart by up to days, the
+    // refined new moon would then move whole months rather than correct a few minutes
+    if (refined - new_moon).abs() > MIDNIGHT_WINDOW {
+        return new_moon;
+    }
+    refined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_moon_meeus() {
+        // Example 49.a of Astronomical Algorithms: the new moon of 1977 February 18
+        let new_moon = new_moon_meeus(-283.0);
+        assert!((new_moon - 2443192.65118).abs() < 0.00001, "{new_moon}");
+    }
+
+    #[test]
+    fn test_refine_new_moon() {
+        // Far from midnight, the AA98 value is kept
+        let k = JulianMonthIndex::from(954);
+        assert_eq!(refine_new_moon(k, 2443192.25, 7.0), 2443192.25);
+        // Close to midnight, the AA98 value is replaced
+        let refined = refine_new_moon(k, 2443192.655, -3.5);
+        assert!((refined - 2443192.65118).abs() < 0.01, "{refined}");
+        // Unless the refined value is too far from it to be a correction
+        assert_eq!(refine_new_moon(k, 2443192.205, 7.0), 2443192.205);
+    }
+}
@@ -0,0 +1,86 @@
+//! The [`Season`] enum and the season, quarter and half-year of a [`NgayTa`].
+
+use crate::NgayTa;
+
+/// Season (mùa) of the traditional calendar, three lunar months each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Season {
+    /// Spring, months 1 to 3.
+    Xuan,
+    /// Summer, months 4 to 6.
+    Ha,
+    /// Autumn, months 7 to 9.
+    Thu,
+    /// Winter, months 10 to 12.
+    Dong,
+}
+
+impl Season {
+    /// Vietnamese name, e.g. "Xuân".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Season::Xuan => "Xuân",
+            Season::Ha => "Hạ",
+            Season::Thu => "Thu",
+            Season::Dong => "Đông",
+        }
+    }
+}
+
+impl NgayTa {
+    /// Get the season of the date from its lunar month.
+    ///
+    /// A leap month belongs to the season of the month it repeats.
+    pub fn season(&self) -> Season {
+        match self.month() {
+            1..=3 => Season::Xuan,
+            4..=6 => Season::Ha,
+            7..=9 => Season::Thu,
+            _ => Season::Dong,
+        }
+    }
+    /// Get the lunar quarter, 1 to 4, of three months each.
+    ///
+    /// A leap month belongs to the quarter of the month it repeats.
+    pub fn lunar_quarter(&self) -> u8 {
+        self.season() as u8 + 1
+    }
+    /// Get the lunar half-year, 1 for months 1 to 6 and 2 for months 7 to 12.
+    ///
+    /// A leap month belongs to the half of the month it repeats.
+    pub fn lunar_half(&self) -> u8 {
+        if self.month() <= 6 {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_season() {
+        assert_eq!(NgayTa::new(1, 1, 2025, false).season(), Season::Xuan);
+        assert_eq!(NgayTa::new(30, 3, 2025, false).season(), Season::Xuan);
+        assert_eq!(NgayTa::new(1, 4, 2025, false).season(), Season::Ha);
+        assert_eq!(NgayTa::new(1, 6, 2025, true).season(), Season::Ha);
+        assert_eq!(NgayTa::new(15, 8, 2025, false).season(), Season::Thu);
+        assert_eq!(NgayTa::new(29, 12, 2025, false).season(), Season::Dong);
+        assert_eq!(Season::Dong.as_str(), "Đông");
+    }
+
+    #[test]
+    fn test_lunar_quarter_and_half() {
+        assert_eq!(NgayTa::new(1, 1, 2025, false).lunar_quarter(), 1);
+        assert_eq!(NgayTa::new(1, 4, 2025, false).lunar_quarter(), 2);
+        assert_eq!(NgayTa::new(1, 6, 2025, true).lunar_quarter(), 2);
+        assert_eq!(NgayTa::new(1, 9, 2025, false).lunar_quarter(), 3);
+        assert_eq!(NgayTa::new(1, 12, 2025, false).lunar_quarter(), 4);
+        assert_eq!(NgayTa::new(1, 6, 2025, true).lunar_half(), 1);
+        assert_eq!(NgayTa::new(1, 7, 2025, false).lunar_half(), 2);
+        assert_eq!(NgayTa::new(1, 12, 2025, false).lunar_half(), 2);
+    }
+}
@@ -0,0 +1,104 @@
+//! The [`SexagenaryDay`] struct, the day pillar in the 60-day Can Chi cycle.
+
+use core::fmt;
+
+use crate::can_chi::{Can, Chi};
+use crate::Date;
+
+/// Position of a day in the 60-day cycle, from 0 (Giáp Tý) to 59 (Quý Hợi), with its Can
+/// and Chi.
+///
+/// Like [`day_can_chi`](crate::day_can_chi), it does not depend on the timezone.
+///
+/// ```
+/// use lich_ta::{Date, SexagenaryDay};
+///
+/// let tet = Date::from_calendar_date(2024, time::Month::February, 10).unwrap();
+/// let day = SexagenaryDay::from_date(tet);
+/// assert_eq!(day.index(), 40);
+/// assert_eq!(day.to_string(), "Giáp Thìn");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SexagenaryDay {
+    index: usize,
+}
+
+impl SexagenaryDay {
+    /// Get the day pillar of a Gregorian date.
+    pub fn from_date(date: Date) -> Self {
+        // Giáp Tý days are 49 days after a multiple of 60 in Julian days
+        Self::from_index(date.to_julian_day() + 49)
+    }
+    /// Get the day of the cycle at `index`, taken modulo 60.
+    pub fn from_index(index: i32) -> Self {
+        Self {
+            index: index.rem_euclid(60) as usize,
+        }
+    }
+}
+
+impl SexagenaryDay {
+    /// Index in the cycle, from 0 (Giáp Tý) to 59 (Quý Hợi).
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    pub fn can(&self) -> Can {
+        Can::from_index(self.index as i32)
+    }
+    pub fn chi(&self) -> Chi {
+        Chi::from_index(self.index as i32)
+    }
+    /// Name of the day, e.g. "Giáp Thìn".
+    #[cfg(feature = "alloc")]
+    pub fn name(&self) -> alloc::string::String {
+        alloc::string::ToString::to_string(self)
+    }
+}
+
+/// Writes the Can and the Chi: "Giáp Thìn".
+impl fmt::Display for SexagenaryDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.can().as_str(), self.chi().as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_can_chi;
+    use time::Month;
+
+    #[test]
+    fn test_known_day_pillars() {
+        let cases = [
+            // Tết Giáp Thìn
+            ((2024, Month::February, 10), 40, (Can::Giap, Chi::Thin)),
+            ((2000, Month::January, 1), 54, (Can::Mau, Chi::Ngo)),
+            ((1970, Month::January, 1), 17, (Can::Tan, Chi::Ti)),
+            // Tết Ất Tỵ
+            ((2025, Month::January, 29), 34, (Can::Mau, Chi::Tuat)),
+        ];
+        for ((year, month, day), index, (can, chi)) in cases {
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            let pillar = SexagenaryDay::from_date(date);
+            assert_eq!(pillar.index(), index, "{date}");
+            assert_eq!((pillar.can(), pillar.chi()), (can, chi), "{date}");
+            assert_eq!((pillar.can(), pillar.chi()), day_can_chi(date), "{date}");
+        }
+    }
+
+    #[test]
+    fn test_from_index() {
+        assert_eq!(SexagenaryDay::from_index(60), SexagenaryDay::from_index(0));
+        assert_eq!(SexagenaryDay::from_index(-1).index(), 59);
+        let last = SexagenaryDay::from_index(59);
+        assert_eq!((last.can(), last.chi()), (Can::Quy, Chi::Hoi));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_name() {
+        assert_eq!(SexagenaryDay::from_index(0).name(), "Giáp Tý");
+        assert_eq!(SexagenaryDay::from_index(40).name(), "Giáp Thìn");
+    }
+}
@@ -0,0 +1,450 @@
+//! The 24 solar terms (tiết khí) and the solar term of a day.
+
+use crate::util::{get_month_start, get_sun_longitude, solar_segment, sun_longitude_aa98};
+use crate::{Date, NgayTa};
+
+/// Sun longitude covered by each solar term, in degrees.
+const SOLAR_TERM_SEGMENT: f64 = 15.0;
+
+/// Mean length of the tropical year, in days.
+const TROPICAL_YEAR: f64 = 365.2422;
+
+/// Vietnamese names of the solar terms, in the order of [`SolarTerm::index`].
+pub const SOLAR_TERM_NAMES: [&str; 24] = [
+    "Xuân Phân",
+    "Thanh Minh",
+    "Cốc Vũ",
+    "Lập Hạ",
+    "Tiểu Mãn",
+    "Mang Chủng",
+    "Hạ Chí",
+    "Tiểu Thử",
+    "Đại Thử",
+    "Lập Thu",
+    "Xử Thử",
+    "Bạch Lộ",
+    "Thu Phân",
+    "Hàn Lộ",
+    "Sương Giáng",
+    "Lập Đông",
+    "Tiểu Tuyết",
+    "Đại Tuyết",
+    "Đông Chí",
+    "Tiểu Hàn",
+    "Đại Hàn",
+    "Lập Xuân",
+    "Vũ Thủy",
+    "Kinh Trập",
+];
+
+/// Solar term (tiết khí), a 15° segment of the sun's longitude.
+///
+/// Variants are in order of longitude, from Xuân Phân at 0° to Kinh Trập at 345°.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarTerm {
+    XuanPhan,
+    ThanhMinh,
+    CocVu,
+    LapHa,
+    TieuMan,
+    MangChung,
+    HaChi,
+    TieuThu,
+    DaiThu,
+    LapThu,
+    XuThu,
+    BachLo,
+    ThuPhan,
+    HanLo,
+    SuongGiang,
+    LapDong,
+    TieuTuyet,
+    DaiTuyet,
+    DongChi,
+    TieuHan,
+    DaiHan,
+    LapXuan,
+    VuThuy,
+    KinhTrap,
+}
+
+impl SolarTerm {
+    const ALL: [SolarTerm; 24] = [
+        SolarTerm::XuanPhan,
+        SolarTerm::ThanhMinh,
+        SolarTerm::CocVu,
+        SolarTerm::LapHa,
+        SolarTerm::TieuMan,
+        SolarTerm::MangChung,
+        SolarTerm::HaChi,
+        SolarTerm::TieuThu,
+        SolarTerm::DaiThu,
+        SolarTerm::LapThu,
+        SolarTerm::XuThu,
+        SolarTerm::BachLo,
+        SolarTerm::ThuPhan,
+        SolarTerm::HanLo,
+        SolarTerm::SuongGiang,
+        SolarTerm::LapDong,
+        SolarTerm::TieuTuyet,
+        SolarTerm::DaiTuyet,
+        SolarTerm::DongChi,
+        SolarTerm::TieuHan,
+        SolarTerm::DaiHan,
+        SolarTerm::LapXuan,
+        SolarTerm::VuThuy,
+        SolarTerm::KinhTrap,
+    ];
+
+    /// Get the term at `index` counted from Xuân Phân, wrapping around every 24.
+    pub fn from_index(index: i32) -> Self {
+        Self::ALL[index.rem_euclid(24) as usize]
+    }
+    /// Get the term the sun is in at `longitude` degrees.
+    pub fn from_longitude(longitude: f64) -> Self {
+        Self::from_index((longitude.rem_euclid(360.0) / SOLAR_TERM_SEGMENT).floor() as i32)
+    }
+    /// Position from 0 (Xuân Phân) to 23 (Kinh Trập).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Sun longitude at which the term begins, in degrees.
+    pub fn longitude(self) -> f64 {
+        self.index() as f64 * SOLAR_TERM_SEGMENT
+    }
+    /// Vietnamese name, e.g. "Lập Xuân".
+    pub fn as_str(self) -> &'static str {
+        SOLAR_TERM_NAMES[self.index()]
+    }
+    /// Whether the term is a major term (trung khí), starting at a multiple of 30°.
+    ///
+    /// The other 12 terms are the minor terms (tiết), only the major ones decide leap months.
+    pub fn is_trung_khi(self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+}
+
+/// Get the solar term in effect at the end of a day.
+///
+/// Parameters:
+/// - `julian_day`: Julian day number of the day.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub(crate) fn get_solar_term(julian_day: i32, timezone: f64) -> SolarTerm {
+    // The sun longitude of the next day is taken at its local midnight, the end of this day
+    let longitude = get_sun_longitude(f64::from(julian_day) + 1.0, timezone);
+    SolarTerm::from_longitude(longitude)
+}
+
+/// Get the solar term of a day, the last one to have begun by the end of the day.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn solar_term(date: Date, timezone: f64) -> SolarTerm {
+    get_solar_term(date.to_julian_day(), timezone)
+}
+
+/// Get the solar term beginning during a day, if any.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn solar_term_starting(date: Date, timezone: f64) -> Option<SolarTerm> {
+    let julian_day = date.to_julian_day();
+    let term = get_solar_term(julian_day, timezone);
+    (term != get_solar_term(julian_day - 1, timezone)).then_some(term)
+}
+
+/// Get the solar term of a day and the number of days since it began.
+///
+/// The day a term begins counts as day 0, so "Lập Xuân + 5 ngày" is `(SolarTerm::LapXuan, 5)`.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn days_into_solar_term(date: Date, timezone: f64) -> (SolarTerm, u16) {
+    let julian_day = date.to_julian_day();
+    let term = get_solar_term(julian_day, timezone);
+    // Terms last 14 to 16 days, the search stops at the day before the term
+    let days = (1..)
+        .find(|&days| get_solar_term(julian_day - days, timezone) != term)
+        .expect("Solar term never ends");
+    (term, (days - 1) as u16)
+}
+
+/// Get the next solar term and the number of days until it begins.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn days_until_next_solar_term(date: Date, timezone: f64) -> (SolarTerm, u16) {
+    let julian_day = date.to_julian_day();
+    let term = get_solar_term(julian_day, timezone);
+    let days = (1..)
+        .find(|&days| get_solar_term(julian_day + days, timezone) != term)
+        .expect("Solar term never ends");
+    (get_solar_term(julian_day + days, timezone), days as u16)
+}
+
+/// Get the major term (trung khí) beginning during a lunar month.
+///
+/// The sun enters a new 30° segment about every 30.4 days, a little slower than the moon
+/// cycle, so now and then a month has no major term. In a 13-month year the first such month
+/// after month 11 is the leap month, this is why it repeats the number of the month before.
+///
+/// Parameters:
+/// - `month_start`: First day of the lunar month, any other day of the month gives the same.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The major term, or `None` for a month without one.
+pub fn trung_khi_of_month(month_start: Date, timezone: f64) -> Option<SolarTerm> {
+    let month_start = get_month_start(month_start.to_julian_day(), timezone);
+    // A month has at most 30 days, 30 days after its start is always in the next month
+    let next_month_start = get_month_start(month_start + 30, timezone);
+    let segment =
+        |julian_day: i32| solar_segment(get_sun_longitude(f64::from(julian_day), timezone));
+    let next_segment = segment(next_month_start);
+    (next_segment != segment(month_start)).then(|| SolarTerm::from_index(next_segment * 2))
+}
+
+/// Get the major term (trung khí) the lunar month of a date takes its number from: month 11
+/// holds Đông Chí, month 12 Đại Hàn, month 1 Vũ Thủy and so on.
+///
+/// A month usually holds one major term, so this is [`trung_khi_of_month`] for the month of
+/// `ngay_ta`. Close to perihelion a month can hold two, the one of its number is returned.
+///
+/// Parameters:
+/// - `ngay_ta`: Any day of the lunar month.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The major term, or `None` for a leap month, which has none, for a month whose
+/// major term fell in the month before it, and for a month that does not exist.
+pub fn governing_trung_khi(ngay_ta: &NgayTa, timezone: f64) -> Option<SolarTerm> {
+    if ngay_ta.is_leap_month() {
+        return None;
+    }
+    let month_start = ngay_ta.month_start_date(timezone).ok()?.to_julian_day();
+    let next_month_start = get_month_start(month_start + 30, timezone);
+    let segment =
+        |julian_day: i32| solar_segment(get_sun_longitude(f64::from(julian_day), timezone));
+    let (first_segment, last_segment) = (segment(month_start), segment(next_month_start));
+    // Month 11 holds the major term starting the segment of 270°, segment 9
+    let own_segment = (ngay_ta.month() - 2).rem_euclid(12);
+    let crossed = (last_segment - first_segment).rem_euclid(12);
+    (1..=crossed)
+        .contains(&(own_segment - first_segment).rem_euclid(12))
+        .then(|| SolarTerm::from_index(own_segment * 2))
+}
+
+/// Get the instant a solar term begins in a Gregorian year, as a day and an hour of that day.
+///
+/// The term begins on the day [`solar_term_starting`] reports it. The hour tells how close to
+/// midnight it is, e.g. to show the minute Lập Xuân begins.
+///
+/// ```
+/// use lich_ta::{solar_term_instant, Date, SolarTerm};
+///
+/// // Lập Xuân 2025 began on February 3 at 21:10 in UTC+7
+/// let (date, hour) = solar_term_instant(2025, SolarTerm::LapXuan, 7.0);
+/// assert_eq!(date, Date::from_calendar_date(2025, time::Month::February, 3).unwrap());
+/// assert!((hour - 21.17).abs() < 0.25);
+/// ```
+///
+/// Parameters:
+/// - `year`: Gregorian year.
+/// - `term`: Solar term.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The local date and the hour within it, from 0.0 to 24.0.
+///
+/// # Panics
+///
+/// Panics if the year is outside the range supported by [`Date`].
+pub fn solar_term_instant(year: i32, term: SolarTerm, timezone: f64) -> (Date, f64) {
+    let march_equinox =
+        Date::from_calendar_date(year, time::Month::March, 20).expect("Invalid date for year");
+    // Tiểu Hàn to Kinh Trập begin in January to early March, before Xuân Phân
+    let mut longitude_after_equinox = term.longitude();
+    if longitude_after_equinox >= SolarTerm::TieuHan.longitude() {
+        longitude_after_equinox -= 360.0;
+    }
+    let mut julian_date =
+        f64::from(march_equinox.to_julian_day()) + longitude_after_equinox / 360.0 * TROPICAL_YEAR;
+    // The sun moves about 1° a day, each step is within minutes of the previous one
+    for _ in 0..10 {
+        let remaining =
+            (term.longitude() - sun_longitude_aa98(julian_date) + 180.0).rem_euclid(360.0) - 180.0;
+        let step = remaining / 360.0 * TROPICAL_YEAR;
+        julian_date += step;
+        if step.abs() < 1e-6 {
+            break;
+        }
+    }
+    // Julian days begin at noon
+    let local = julian_date + 0.5 + timezone / 24.0;
+    let date = Date::from_julian_day(local.floor() as i32).expect("Invalid date for year");
+    (date, (local - local.floor()) * 24.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_solar_term_instant() {
+        // The day of the instant is the day the term begins
+        for year in [1900, 1968, 2000, 2024, 2025, 2099] {
+            for term in SolarTerm::ALL {
+                let (date, hour) = solar_term_instant(year, term, 7.0);
+                assert_eq!(date.year(), year, "{term:?} {year}");
+                assert!((0.0..24.0).contains(&hour), "{term:?} {year}: {hour}");
+                assert_eq!(
+                    solar_term_starting(date, 7.0),
+                    Some(term),
+                    "{term:?} {year}"
+                );
+            }
+        }
+        // Đông Chí 2024 began on 2024-12-21 at 09:20 UTC, 16:20 in UTC+7
+        let (date, hour) = solar_term_instant(2024, SolarTerm::DongChi, 7.0);
+        assert_eq!(
+            date,
+            Date::from_calendar_date(2024, Month::December, 21).unwrap()
+        );
+        assert!((hour - 16.34).abs() < 0.25, "{hour}");
+        // Same instant in another timezone, a day earlier
+        let (date, hour_utc_minus_10) = solar_term_instant(2024, SolarTerm::DongChi, -10.0);
+        assert_eq!(
+            date,
+            Date::from_calendar_date(2024, Month::December, 20).unwrap()
+        );
+        assert!((hour_utc_minus_10 - (hour - 17.0 + 24.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_from_longitude() {
+        assert_eq!(SolarTerm::from_longitude(0.0), SolarTerm::XuanPhan);
+        assert_eq!(SolarTerm::from_longitude(316.2), SolarTerm::LapXuan);
+        assert_eq!(SolarTerm::from_longitude(-45.0), SolarTerm::LapXuan);
+        assert_eq!(SolarTerm::from_longitude(359.9), SolarTerm::KinhTrap);
+        assert_eq!(SolarTerm::DongChi.longitude(), 270.0);
+        assert_eq!(SolarTerm::LapXuan.as_str(), "Lập Xuân");
+    }
+
+    #[test]
+    fn test_solar_term_starting() {
+        let date = Date::from_calendar_date(2024, Month::February, 4).unwrap();
+        assert_eq!(solar_term_starting(date, 7.0), Some(SolarTerm::LapXuan));
+        let date = Date::from_calendar_date(2025, Month::February, 3).unwrap();
+        assert_eq!(solar_term_starting(date, 7.0), Some(SolarTerm::LapXuan));
+        let date = Date::from_calendar_date(2024, Month::December, 21).unwrap();
+        assert_eq!(solar_term_starting(date, 7.0), Some(SolarTerm::DongChi));
+        assert_eq!(solar_term_starting(date.next_day().unwrap(), 7.0), None);
+        assert_eq!(
+            solar_term(date.next_day().unwrap(), 7.0),
+            SolarTerm::DongChi
+        );
+
+        // Before 2000 the sun longitude has to be brought back into 0..360 too
+        let date = Date::from_calendar_date(1990, Month::April, 5).unwrap();
+        assert_eq!(solar_term_starting(date, 7.0), Some(SolarTerm::ThanhMinh));
+    }
+
+    #[test]
+    fn test_is_trung_khi() {
+        assert!(SolarTerm::XuanPhan.is_trung_khi());
+        assert!(SolarTerm::DongChi.is_trung_khi());
+        assert!(!SolarTerm::LapXuan.is_trung_khi());
+        assert_eq!(
+            (0..24)
+                .map(SolarTerm::from_index)
+                .filter(|t| t.is_trung_khi())
+                .count(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_trung_khi_of_month() {
+        // Month 6 of 2025 holds Đại Thử, the leap month 6 after it has no major term
+        let month_6 = Date::from_calendar_date(2025, Month::June, 25).unwrap();
+        assert_eq!(trung_khi_of_month(month_6, 7.0), Some(SolarTerm::DaiThu));
+        let leap_month_6 = Date::from_calendar_date(2025, Month::July, 25).unwrap();
+        assert_eq!(trung_khi_of_month(leap_month_6, 7.0), None);
+        let day_in_leap_month = Date::from_calendar_date(2025, Month::August, 10).unwrap();
+        assert_eq!(trung_khi_of_month(day_in_leap_month, 7.0), None);
+
+        // Month 11 always holds Đông Chí
+        let month_11 = Date::from_calendar_date(2025, Month::December, 20).unwrap();
+        assert_eq!(trung_khi_of_month(month_11, 7.0), Some(SolarTerm::DongChi));
+    }
+
+    #[test]
+    fn test_days_into_solar_term() {
+        // Lập Xuân began on 2024-02-04, Vũ Thủy on 2024-02-19
+        let lap_xuan = Date::from_calendar_date(2024, Month::February, 4).unwrap();
+        assert_eq!(days_into_solar_term(lap_xuan, 7.0), (SolarTerm::LapXuan, 0));
+        let date = Date::from_calendar_date(2024, Month::February, 9).unwrap();
+        assert_eq!(days_into_solar_term(date, 7.0), (SolarTerm::LapXuan, 5));
+        assert_eq!(
+            days_until_next_solar_term(date, 7.0),
+            (SolarTerm::VuThuy, 10)
+        );
+        assert_eq!(
+            days_until_next_solar_term(lap_xuan.previous_day().unwrap(), 7.0),
+            (SolarTerm::LapXuan, 1)
+        );
+    }
+
+    #[test]
+    fn test_name_table() {
+        assert_eq!(SOLAR_TERM_NAMES.len(), 24);
+        for (i, name) in SOLAR_TERM_NAMES.iter().enumerate() {
+            assert!(!name.is_empty());
+            assert!(!SOLAR_TERM_NAMES[..i].contains(name), "{name} is repeated");
+        }
+        for (i, term) in SolarTerm::ALL.iter().enumerate() {
+            assert_eq!(term.index(), i);
+        }
+        assert_eq!(SolarTerm::XuanPhan.as_str(), "Xuân Phân");
+        assert_eq!(SolarTerm::DongChi.as_str(), "Đông Chí");
+        assert_eq!(SolarTerm::KinhTrap.as_str(), "Kinh Trập");
+    }
+
+    #[test]
+    fn test_governing_trung_khi() {
+        for year in 1900..2100 {
+            let month_11 = NgayTa::new(1, 11, year, false);
+            assert_eq!(
+                governing_trung_khi(&month_11, 7.0),
+                Some(SolarTerm::DongChi),
+                "{year}"
+            );
+        }
+        assert_eq!(
+            governing_trung_khi(&NgayTa::new(15, 1, 2025, false), 7.0),
+            Some(SolarTerm::VuThuy)
+        );
+        // Month 11 of 2052 holds both Đông Chí and Đại Hàn
+        let month_11 = NgayTa::new(1, 11, 2052, false);
+        let month_start = month_11.month_start_date(7.0).unwrap();
+        assert_eq!(
+            trung_khi_of_month(month_start, 7.0),
+            Some(SolarTerm::DaiHan)
+        );
+        assert_eq!(
+            governing_trung_khi(&month_11, 7.0),
+            Some(SolarTerm::DongChi)
+        );
+        assert_eq!(
+            governing_trung_khi(&NgayTa::new(1, 6, 2025, true), 7.0),
+            None
+        );
+        // 2024 has no leap month 6
+        assert_eq!(
+            governing_trung_khi(&NgayTa::new(1, 6, 2024, true), 7.0),
+            None
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Conversions in apparent solar time, behind the `solar-time` feature.
+//!
+//! The other APIs start the days at midnight of a timezone, a uniform clock. Classical
+//! almanacs started them at midnight of the apparent sun at a reference meridian, which runs
+//! up to about 16 minutes ahead of or behind the clock over the year: the equation of time.
+//! Only new moons and solar terms within those minutes of midnight are affected, so the
+//! months they start may move by a day.
+
+use crate::util::{convert_date_to_lichta_with, gregorian_of_lunar_day_with, LocalTime, Uncached};
+use crate::{Date, LichTaError, NgayTa};
+
+/// Julian day of 2000-01-01 12:00 UTC, the epoch of the series below.
+const J2000: f64 = 2451545.0;
+const JULIAN_CENTURY: f64 = 36525.0;
+
+/// Get the equation of time, apparent minus mean solar time, in minutes.
+///
+/// Uses the formula of Smart, as given in Meeus' *Astronomical Algorithms* (chapter 28),
+/// accurate to a few seconds.
+///
+/// Parameters:
+/// - `julian_date`: Fractional Julian day in UTC.
+fn equation_of_time(julian_date: f64) -> f64 {
+    let t = (julian_date - J2000) / JULIAN_CENTURY;
+    let mean_longitude = (280.46646 + 36000.76983 * t + 0.0003032 * t * t).to_radians();
+    let mean_anomaly = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let eccentricity = 0.016708634 - 0.000042037 * t - 0.0000001267 * t * t;
+    let obliquity = (23.439291 - 0.0130042 * t).to_radians();
+    let y = f64::tan(obliquity / 2.0).powi(2);
+
+    let equation = y * f64::sin(2.0 * mean_longitude) - 2.0 * eccentricity * f64::sin(mean_anomaly)
+        + 4.0 * eccentricity * y * f64::sin(mean_anomaly) * f64::cos(2.0 * mean_longitude)
+        - 0.5 * y * y * f64::sin(4.0 * mean_longitude)
+        - 1.25 * eccentricity * eccentricity * f64::sin(2.0 * mean_anomaly);
+    // One degree of hour angle is 4 minutes
+    equation.to_degrees() * 4.0
+}
+
+/// Time the days start in: the clock of a timezone or the apparent sun at a meridian.
+///
+/// ```
+/// use lich_ta::{convert_date_to_lichta, Date, SolarTimeModel};
+///
+/// let date = Date::from_calendar_date(2025, time::Month::July, 25).unwrap();
+/// let model = SolarTimeModel::ApparentMeridian { longitude_deg: 105.0 };
+/// assert_eq!(model.convert(date), convert_date_to_lichta(date, 7.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SolarTimeModel {
+    /// Midnight of a timezone, in hours from UTC, the same as passing `timezone` to the
+    /// other APIs.
+    MeanZone { timezone: f64 },
+    /// Midnight of the apparent sun at a longitude, in degrees east of Greenwich.
+    ApparentMeridian { longitude_deg: f64 },
+}
+
+impl LocalTime for SolarTimeModel {
+    fn offset_hours(self, julian_date: f64) -> f64 {
+        match self {
+            SolarTimeModel::MeanZone { timezone } => timezone,
+            SolarTimeModel::ApparentMeridian { longitude_deg } => {
+                longitude_deg / 15.0 + equation_of_time(julian_date) / 60.0
+            }
+        }
+    }
+}
+
+impl SolarTimeModel {
+    /// Convert a Gregorian date to the LichTa calendar, like [`NgayTa::from_date`].
+    ///
+    /// # Panics
+    ///
+    /// May panic for a date outside [`supported_date_range`](crate::supported_date_range).
+    pub fn convert(self, date: Date) -> NgayTa {
+        let (day, month, year, is_leap_month) =
+            convert_date_to_lichta_with(date, self, &mut Uncached(self)).to_tuple();
+        NgayTa::new(day, month, year, is_leap_month == 1)
+    }
+
+    /// Convert a LichTa date back to the Gregorian calendar, like [`NgayTa::to_date`].
+    pub fn to_date(self, ngay_ta: NgayTa) -> Result<Date, LichTaError> {
+        gregorian_of_lunar_day_with(
+            ngay_ta.year(),
+            ngay_ta.month(),
+            ngay_ta.is_leap_month(),
+            ngay_ta.day(),
+            self,
+            &mut Uncached(self),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_date_to_lichta;
+
+    #[test]
+    fn test_equation_of_time() {
+        // Example 28.b of Astronomical Algorithms: 1992 October 13 0h TD, 13m 42.7s
+        let minutes = equation_of_time(2448908.5);
+        assert!((minutes - 13.711).abs() < 0.01, "{minutes}");
+        // Close to its extremes in February and November
+        let february = Date::from_calendar_date(2025, time::Month::February, 11).unwrap();
+        let minutes = equation_of_time(february.to_julian_day().into());
+        assert!((-14.5..-14.0).contains(&minutes), "{minutes}");
+        let november = Date::from_calendar_date(2025, time::Month::November, 3).unwrap();
+        let minutes = equation_of_time(november.to_julian_day().into());
+        assert!((16.0..16.5).contains(&minutes), "{minutes}");
+    }
+
+    #[test]
+    fn test_mean_zone_matches_timezone() {
+        let model = SolarTimeModel::MeanZone { timezone: 7.0 };
+        let mut date = Date::from_calendar_date(2025, time::Month::January, 1).unwrap();
+        while date.year() == 2025 {
+            let ngay_ta = model.convert(date);
+            assert_eq!(ngay_ta, convert_date_to_lichta(date, 7.0), "{date}");
+            assert_eq!(model.to_date(ngay_ta), Ok(date));
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_apparent_meridian_shifts_borderline_month() {
+        // The new moon of 1995-09-24 is minutes before midnight in UTC+7, already after it
+        // in apparent solar time with the sun 8 minutes ahead of the clock
+        let date = Date::from_calendar_date(1995, time::Month::September, 24).unwrap();
+        let model = SolarTimeModel::ApparentMeridian {
+            longitude_deg: 105.0,
+        };
+        assert_eq!(convert_date_to_lichta(date, 7.0), (1, 8, 1995, 1));
+        assert_eq!(model.convert(date), NgayTa::new(30, 8, 1995, false));
+        let next_day = date.next_day().unwrap();
+        assert_eq!(model.convert(next_day), NgayTa::new(1, 8, 1995, true));
+        assert_eq!(model.to_date(NgayTa::new(1, 8, 1995, true)), Ok(next_day));
+    }
+}
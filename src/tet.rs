@@ -0,0 +1,128 @@
+//! Lunar New Year (Tết) dates.
+
+use crate::util::{date_from_julian_day, get_lunar_month_span};
+use crate::Date;
+#[cfg(feature = "alloc")]
+use crate::{Converter, NgayTa};
+
+/// Get the Gregorian date of Tết, the first day of month 1 of the lunar `year`.
+///
+/// Parameters:
+/// - `year`: Lunar year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// # Panics
+///
+/// Panics if `year` is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+pub fn tet_date(year: i32, timezone: f64) -> Date {
+    get_lunar_month_span(1, year, false, timezone)
+        .ok()
+        .and_then(|(month_start, _)| date_from_julian_day(month_start))
+        .expect("Invalid date for year")
+}
+
+/// Get the Gregorian date of Tết for each lunar year from `start_year` to `end_year`, both
+/// included, e.g. to build a table of Tết dates.
+///
+/// The years share one [`Converter`], so each month 11 is computed once. The dates are the
+/// same as [`tet_date`]'s.
+///
+/// Parameters:
+/// - `start_year`: First lunar year.
+/// - `end_year`: Last lunar year, no year is returned when it is before `start_year`.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: (lunar year, date of Tết) pairs in the order of the years.
+///
+/// # Panics
+///
+/// Panics if a year is outside the lunar years of [`supported_date_range`](crate::supported_date_range).
+#[cfg(feature = "alloc")]
+pub fn tet_dates(start_year: i32, end_year: i32, timezone: f64) -> alloc::vec::Vec<(i32, Date)> {
+    let mut converter = Converter::new(timezone);
+    (start_year..=end_year)
+        .map(|year| {
+            let tet = converter
+                .to_date(NgayTa::new(1, 1, year, false))
+                .expect("Invalid date for year");
+            (year, tet)
+        })
+        .collect()
+}
+
+/// Get the number of days from `today` to the next Tết, 0 if `today` is Tết.
+///
+/// Tết always falls in January or February, so the next one is either this Gregorian year's
+/// or, once it has passed, the next year's.
+///
+/// Parameters:
+/// - `today`: Gregorian date to count from.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub fn days_until_tet(today: Date, timezone: f64) -> i64 {
+    let mut tet = tet_date(today.year(), timezone);
+    if tet < today {
+        tet = tet_date(today.year() + 1, timezone);
+    }
+    (tet - today).whole_days()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_tet_date() {
+        assert_eq!(
+            tet_date(2024, 7.0),
+            Date::from_calendar_date(2024, Month::February, 10).unwrap()
+        );
+        assert_eq!(
+            tet_date(2025, 7.0),
+            Date::from_calendar_date(2025, Month::January, 29).unwrap()
+        );
+        assert_eq!(
+            tet_date(2026, 7.0),
+            Date::from_calendar_date(2026, Month::February, 17).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_tet_dates() {
+        let dates = tet_dates(2024, 2026, 7.0);
+        assert_eq!(
+            dates,
+            [
+                (
+                    2024,
+                    Date::from_calendar_date(2024, Month::February, 10).unwrap()
+                ),
+                (
+                    2025,
+                    Date::from_calendar_date(2025, Month::January, 29).unwrap()
+                ),
+                (
+                    2026,
+                    Date::from_calendar_date(2026, Month::February, 17).unwrap()
+                ),
+            ]
+        );
+        for (year, tet) in tet_dates(1900, 2100, 7.0) {
+            assert_eq!(tet, tet_date(year, 7.0), "{year}");
+        }
+        assert!(tet_dates(2025, 2024, 7.0).is_empty());
+    }
+
+    #[test]
+    fn test_days_until_tet() {
+        let date = Date::from_calendar_date(2025, Month::January, 28).unwrap();
+        assert_eq!(days_until_tet(date, 7.0), 1);
+        let date = Date::from_calendar_date(2025, Month::January, 29).unwrap();
+        assert_eq!(days_until_tet(date, 7.0), 0);
+        let date = Date::from_calendar_date(2025, Month::January, 30).unwrap();
+        assert_eq!(days_until_tet(date, 7.0), 383);
+        let date = Date::from_calendar_date(2025, Month::December, 31).unwrap();
+        assert_eq!(days_until_tet(date, 7.0), 48);
+    }
+}
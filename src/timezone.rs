@@ -0,0 +1,50 @@
+//! The [`Timezone`] newtype and its regional presets.
+
+/// Timezone offset from UTC in hours, as taken by the `timezone` parameters.
+///
+/// ```
+/// use lich_ta::{convert_date_to_lichta, Date, Timezone};
+///
+/// let date = Date::from_calendar_date(2024, time::Month::May, 24).unwrap();
+/// let (day, month, _, _) = convert_date_to_lichta(date, Timezone::VIETNAM.hours());
+/// assert_eq!((day, month), (17, 4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Timezone(pub f64);
+
+impl Timezone {
+    /// UTC+7, the meridian of the Vietnamese calendar since 1968.
+    pub const VIETNAM: Timezone = Timezone(7.0);
+    /// UTC+8, the meridian of the Chinese calendar.
+    pub const CHINA: Timezone = Timezone(8.0);
+    /// UTC+9, the meridian of the Korean calendar.
+    pub const KOREA: Timezone = Timezone(9.0);
+    /// UTC+9, the meridian of the Japanese calendar.
+    pub const JAPAN: Timezone = Timezone(9.0);
+    /// UTC, the meridian of Greenwich.
+    pub const UTC: Timezone = Timezone(0.0);
+
+    /// Offset from UTC in hours.
+    pub const fn hours(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<Timezone> for f64 {
+    fn from(timezone: Timezone) -> Self {
+        timezone.hours()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(Timezone::VIETNAM.hours(), 7.0);
+        assert_eq!(f64::from(Timezone::CHINA), 8.0);
+        assert_eq!(Timezone::KOREA, Timezone::JAPAN);
+        assert_eq!(Timezone::UTC.hours(), 0.0);
+    }
+}
@@ -0,0 +1,122 @@
+//! The twelve day officers (thập nhị trực) of the almanac.
+
+use crate::can_chi::{day_can_chi, Chi};
+use crate::{Date, NgayTa};
+
+/// Day officer (trực), cycling with the day Chi from Kiến, the day sharing the Chi of its month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Truc {
+    Kien,
+    Tru,
+    Man,
+    Binh,
+    Dinh,
+    Chap,
+    Pha,
+    Nguy,
+    Thanh,
+    Thu,
+    Khai,
+    Be,
+}
+
+impl Truc {
+    const ALL: [Truc; 12] = [
+        Truc::Kien,
+        Truc::Tru,
+        Truc::Man,
+        Truc::Binh,
+        Truc::Dinh,
+        Truc::Chap,
+        Truc::Pha,
+        Truc::Nguy,
+        Truc::Thanh,
+        Truc::Thu,
+        Truc::Khai,
+        Truc::Be,
+    ];
+
+    /// Get the officer at `index` in the cycle starting from Kiến, wrapping around every 12.
+    pub fn from_index(index: i32) -> Self {
+        Self::ALL[index.rem_euclid(12) as usize]
+    }
+    /// Position in the cycle, from 0 (Kiến) to 11 (Bế).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Vietnamese name, e.g. "Kiến".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Truc::Kien => "Kiến",
+            Truc::Tru => "Trừ",
+            Truc::Man => "Mãn",
+            Truc::Binh => "Bình",
+            Truc::Dinh => "Định",
+            Truc::Chap => "Chấp",
+            Truc::Pha => "Phá",
+            Truc::Nguy => "Nguy",
+            Truc::Thanh => "Thành",
+            Truc::Thu => "Thu",
+            Truc::Khai => "Khai",
+            Truc::Be => "Bế",
+        }
+    }
+}
+
+/// Get the Chi of a lunar month: Dần for month 1, up to Sửu for month 12.
+///
+/// A leap month has the Chi of the month it repeats.
+fn month_chi(ngay_ta: &NgayTa) -> Chi {
+    Chi::from_index(ngay_ta.month() + 1)
+}
+
+/// Get the day officer (trực) of a day, from `(day_chi - month_chi) % 12`.
+///
+/// Parameters:
+/// - `ngay_ta`: Lunar date of `date`, giving the month.
+/// - `date`: Gregorian date, giving the day Chi.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST), only used to
+///   check in debug builds that `ngay_ta` is the lunar date of `date`.
+pub fn truc_of_day(ngay_ta: &NgayTa, date: Date, timezone: f64) -> Truc {
+    debug_assert_eq!(
+        NgayTa::from_date(date, timezone),
+        *ngay_ta,
+        "Lunar date does not match the Gregorian date"
+    );
+    let (_, day_chi) = day_can_chi(date);
+    Truc::from_index(day_chi.index() as i32 - month_chi(ngay_ta).index() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_truc_of_day() {
+        // Tết Giáp Thìn: a Thìn day in the Dần month
+        let date = Date::from_calendar_date(2024, Month::February, 10).unwrap();
+        let ngay_ta = NgayTa::from_date(date, 7.0);
+        assert_eq!(truc_of_day(&ngay_ta, date, 7.0), Truc::Man);
+        assert_eq!(Truc::Man.as_str(), "Mãn");
+
+        // The Dần day of month 1 is Kiến, the officers follow day by day
+        let kien = Date::from_calendar_date(2024, Month::February, 20).unwrap();
+        let ngay_ta = NgayTa::from_date(kien, 7.0);
+        assert_eq!(day_can_chi(kien).1, Chi::Dan);
+        assert_eq!(truc_of_day(&ngay_ta, kien, 7.0), Truc::Kien);
+        let next = kien.next_day().unwrap();
+        assert_eq!(
+            truc_of_day(&NgayTa::from_date(next, 7.0), next, 7.0),
+            Truc::Tru
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Lunar date does not match the Gregorian date")]
+    fn test_truc_of_day_mismatch() {
+        let date = Date::from_calendar_date(2024, Month::February, 10).unwrap();
+        truc_of_day(&NgayTa::new(2, 1, 2024, false), date, 7.0);
+    }
+}
@@ -0,0 +1,56 @@
+//! The [`Tuan`] enum, the thirds of a lunar month.
+
+use crate::NgayTa;
+
+/// Third (tuần) of a lunar month, as in "hạ tuần tháng Chạp".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tuan {
+    /// Days 1 to 10.
+    ThuongTuan,
+    /// Days 11 to 20.
+    TrungTuan,
+    /// Days 21 to the end of the month, 29 or 30.
+    HaTuan,
+}
+
+impl Tuan {
+    /// Vietnamese name, e.g. "Thượng tuần".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Tuan::ThuongTuan => "Thượng tuần",
+            Tuan::TrungTuan => "Trung tuần",
+            Tuan::HaTuan => "Hạ tuần",
+        }
+    }
+}
+
+impl NgayTa {
+    /// Get the third of the month the day falls in.
+    ///
+    /// The last third runs to the end of the month, so it has 9 days in a 29-day month and
+    /// 10 in a 30-day one.
+    pub fn tuan(&self) -> Tuan {
+        match self.day() {
+            ..=10 => Tuan::ThuongTuan,
+            11..=20 => Tuan::TrungTuan,
+            _ => Tuan::HaTuan,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tuan() {
+        assert_eq!(NgayTa::new(1, 12, 2024, false).tuan(), Tuan::ThuongTuan);
+        assert_eq!(NgayTa::new(10, 12, 2024, false).tuan(), Tuan::ThuongTuan);
+        assert_eq!(NgayTa::new(11, 12, 2024, false).tuan(), Tuan::TrungTuan);
+        assert_eq!(NgayTa::new(20, 12, 2024, false).tuan(), Tuan::TrungTuan);
+        assert_eq!(NgayTa::new(21, 12, 2024, false).tuan(), Tuan::HaTuan);
+        assert_eq!(NgayTa::new(29, 12, 2024, false).tuan(), Tuan::HaTuan);
+        assert_eq!(NgayTa::new(30, 12, 2024, false).tuan(), Tuan::HaTuan);
+        assert_eq!(Tuan::HaTuan.as_str(), "Hạ tuần");
+    }
+}
@@ -0,0 +1,102 @@
+//! The [`LichTaTuple`] newtype over the tuple of [`convert_date_to_lichta`].
+//!
+//! [`convert_date_to_lichta`]: crate::convert_date_to_lichta
+
+use core::fmt;
+
+use crate::NgayTa;
+
+/// The `(day, month, year, leap)` tuple returned by [`convert_date_to_lichta`], with
+/// accessors and the [`Display`](fmt::Display) form of [`NgayTa`].
+///
+/// ```
+/// use lich_ta::{convert_date_to_lichta, Date, LichTaTuple, NgayTa};
+///
+/// let date = Date::from_calendar_date(2025, time::Month::July, 25).unwrap();
+/// let tuple = LichTaTuple::from(convert_date_to_lichta(date, 7.0));
+/// assert_eq!(tuple.to_string(), "1/6+/2025");
+/// assert_eq!(NgayTa::from(tuple), NgayTa::new(1, 6, 2025, true));
+/// ```
+///
+/// [`convert_date_to_lichta`]: crate::convert_date_to_lichta
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LichTaTuple(pub (i32, i32, i32, i32));
+
+impl LichTaTuple {
+    pub fn day(&self) -> i32 {
+        self.0 .0
+    }
+    pub fn month(&self) -> i32 {
+        self.0 .1
+    }
+    pub fn year(&self) -> i32 {
+        self.0 .2
+    }
+    /// 1 for a leap month else 0, as in the tuple.
+    pub fn leap(&self) -> i32 {
+        self.0 .3
+    }
+    pub fn is_leap_month(&self) -> bool {
+        self.leap() == 1
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for LichTaTuple {
+    fn from(tuple: (i32, i32, i32, i32)) -> Self {
+        Self(tuple)
+    }
+}
+
+impl From<LichTaTuple> for (i32, i32, i32, i32) {
+    fn from(tuple: LichTaTuple) -> Self {
+        tuple.0
+    }
+}
+
+impl From<LichTaTuple> for NgayTa {
+    fn from(tuple: LichTaTuple) -> Self {
+        NgayTa::new(
+            tuple.day(),
+            tuple.month(),
+            tuple.year(),
+            tuple.is_leap_month(),
+        )
+    }
+}
+
+/// Writes the date like [`NgayTa`]: "17/4/2024", "1/6+/2025".
+impl fmt::Display for LichTaTuple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        NgayTa::from(*self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lich_ta_tuple() {
+        let tuple = LichTaTuple::from((17, 4, 2024, 0));
+        assert_eq!(
+            (tuple.day(), tuple.month(), tuple.year(), tuple.leap()),
+            (17, 4, 2024, 0)
+        );
+        assert!(!tuple.is_leap_month());
+        assert_eq!(NgayTa::from(tuple), NgayTa::new(17, 4, 2024, false));
+        assert_eq!(<(i32, i32, i32, i32)>::from(tuple), (17, 4, 2024, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display() {
+        assert_eq!(
+            alloc::format!("{}", LichTaTuple((17, 4, 2024, 0))),
+            "17/4/2024"
+        );
+        assert_eq!(
+            alloc::format!("{}", LichTaTuple((1, 6, 2025, 1))),
+            "1/6+/2025"
+        );
+    }
+}
@@ -1,59 +1,53 @@
 //! Utility functions.
 
-use crate::Date;
-use core::ops::{Add, Deref, Sub};
+use crate::{Date, LichTaError, NgayTa};
+use core::ops::RangeInclusive;
+
+/// Local time the days of the calendar start in, as an offset from UTC.
+///
+/// A timezone is a fixed offset in hours. The offset may also depend on the instant, for
+/// the `solar-time` feature's apparent solar time.
+pub(crate) trait LocalTime: Copy {
+    /// Offset from UTC in hours at the instant `julian_date`, a fractional Julian day in UTC.
+    fn offset_hours(self, julian_date: f64) -> f64;
+}
+
+impl LocalTime for f64 {
+    fn offset_hours(self, _julian_date: f64) -> f64 {
+        self
+    }
+}
 
 /// Number of **Julian Month** since mid-day 1/1/1900 (julian day: 2415021).
-#[derive(Clone, Copy, Debug)]
-struct JulianMonthIndex(pub i32);
+///
+/// The `k` of the new moon series, see [`lunar_month_to_k`] and [`new_moon_day`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JulianMonthIndex(i32);
 
 const JULIAN_MOON_CYCLE: f64 = 29.530588853;
 
 impl JulianMonthIndex {
-    pub fn from_julian_day(value: f64) -> Self {
+    pub(crate) fn from_julian_day(value: f64) -> Self {
         let offset = value - JULIAN_DAY_NOON_JAN_1_1900;
-        let k_value = (offset / JULIAN_MOON_CYCLE) as i32;
+        // Floor rather than truncate, months before 1900 have a negative index
+        let k_value = (offset / JULIAN_MOON_CYCLE).floor() as i32;
         Self(k_value)
     }
-}
-
-impl JulianMonthIndex {
-    pub fn new(value: i32) -> JulianMonthIndex {
-        JulianMonthIndex(value)
-    }
-}
 
-impl Into<f64> for JulianMonthIndex {
-    fn into(self) -> f64 {
-        let value = self.0;
-        value.into()
+    /// Number of new moons since the one of 1900-01-01, negative before it.
+    pub fn value(self) -> i32 {
+        self.0
     }
-}
 
-impl Add for JulianMonthIndex {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        let k = *self + *rhs;
-        Self(k)
+    /// The index `months` new moons after this one, before it for a negative `months`.
+    pub(crate) fn offset(self, months: i32) -> Self {
+        Self(self.0 + months)
     }
 }
-impl Sub for JulianMonthIndex {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        let k = *self - *rhs;
-        Self(k)
-    }
-}
-
-impl From<f64> for JulianMonthIndex {
-    fn from(value: f64) -> Self {
-        let value = if value <= i32::MAX.into() {
-            value as i32
-        } else {
-            panic!("K value is greater than i32::MAX")
-        };
 
-        Self(value)
+impl From<JulianMonthIndex> for f64 {
+    fn from(value: JulianMonthIndex) -> Self {
+        value.0.into()
     }
 }
 
@@ -63,13 +57,6 @@ impl From<i32> for JulianMonthIndex {
     }
 }
 
-impl Deref for JulianMonthIndex {
-    type Target = i32;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
 /// Astronomical constants
 const JULIAN_CENTURY: f64 = 36525.0;
 const EPOCH_2000_12: f64 = 2451545.0;
@@ -95,9 +82,9 @@ const EQUATION_CENTER_SECOND_HARMONIC: f64 = 0.000290;
 
 const JULIAN_DAY_NOON_JAN_1_1900: f64 = 2415021.076998695;
 
-/// Normalize an angle in degrees to the range (0, 360)
+/// Normalize an angle in degrees to the range [0, 360), negative angles included
 fn normalize_longitude(longitude: f64) -> f64 {
-    longitude % 360.0
+    longitude.rem_euclid(360.0)
 }
 
 /// Calculate sun's position in the sky
@@ -108,7 +95,8 @@ fn normalize_longitude(longitude: f64) -> f64 {
 /// - `jdn`: Julian day at **12:00:00**
 ///
 /// Return: degrees value from 0.0 to 360.0
-fn sun_longitude_aa98(jdn: f64) -> f64 {
+pub(crate) fn sun_longitude_aa98(jdn: f64) -> f64 {
+    debug_assert!(jdn.is_finite(), "Julian day must be finite, got {jdn}");
     // Time in Julian centuries from the epoch 2000-01-01 12:00:00
     let t = (jdn - EPOCH_2000_12) / JULIAN_CENTURY;
     let t_2 = t * t;
@@ -139,9 +127,9 @@ fn sun_longitude_aa98(jdn: f64) -> f64 {
 /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
 ///
 /// Return: Sun's longitude in degrees from 0.0 to 360.0.
-fn get_sun_longitude(jdn: f64, timezone: f64) -> f64 {
+pub(crate) fn get_sun_longitude(jdn: f64, timezone: impl LocalTime) -> f64 {
     // Align with timezone
-    let jdn_adjusted = jdn - 0.5 - timezone / 24.0;
+    let jdn_adjusted = jdn - 0.5 - timezone.offset_hours(jdn - 0.5) / 24.0;
     sun_longitude_aa98(jdn_adjusted)
 }
 
@@ -153,6 +141,10 @@ fn get_sun_longitude(jdn: f64, timezone: f64) -> f64 {
 /// Return: Julian day
 fn new_moon_aa98(julian_month_index: JulianMonthIndex) -> f64 {
     let julian_month_index: f64 = julian_month_index.into();
+    debug_assert!(
+        julian_month_index.is_finite(),
+        "Julian month index must be finite, got {julian_month_index}"
+    );
     // Time in Julian centuries from 1900 January 0.5
     let t = julian_month_index / 1236.85;
     let t_2 = t * t;
@@ -166,27 +158,49 @@ fn new_moon_aa98(julian_month_index: JulianMonthIndex) -> f64 {
         306.0253 + 385.81691806 * julian_month_index + 0.0107306 * t_2 + 0.00001236 * t_3; // Moon's mean anomaly
     let moon_argument_latitude =
         21.2964 + 390.67050646 * julian_month_index - 0.0016528 * t_2 - 0.00000239 * t_3; // Moon's argument of latitude
-    let mut lunar_correction = (0.1734 - 0.000393 * t) * f64::sin(sun_mean_anomaly.to_radians())
-        + 0.0021 * f64::sin(2.0 * sun_mean_anomaly.to_radians());
-    lunar_correction -= 0.4068 * f64::sin(moon_mean_anomaly.to_radians())
-        + 0.0161 * f64::sin(2.0 * moon_mean_anomaly.to_radians());
+    let mut lunar_correction = (0.1734 - 0.000393 * t) * f64::sin(sun_mean_anomaly.to_radians());
+    lunar_correction += 0.0021 * f64::sin(2.0 * sun_mean_anomaly.to_radians());
+    lunar_correction -= 0.4068 * f64::sin(moon_mean_anomaly.to_radians());
+    lunar_correction += 0.0161 * f64::sin(2.0 * moon_mean_anomaly.to_radians());
     lunar_correction -= 0.0004 * f64::sin(3.0 * moon_mean_anomaly.to_radians());
-    lunar_correction += 0.0104 * f64::sin(2.0 * moon_argument_latitude.to_radians())
-        - 0.0051 * f64::sin((sun_mean_anomaly + moon_mean_anomaly).to_radians());
-    lunar_correction -= 0.0074 * f64::sin((sun_mean_anomaly - moon_mean_anomaly).to_radians())
-        + 0.0004 * f64::sin((2.0 * moon_argument_latitude + sun_mean_anomaly).to_radians());
-    lunar_correction -= 0.0004
-        * f64::sin((2.0 * moon_argument_latitude - sun_mean_anomaly).to_radians())
-        - 0.0006 * f64::sin((2.0 * moon_argument_latitude + moon_mean_anomaly).to_radians());
-    lunar_correction += 0.0010
-        * f64::sin((2.0 * moon_argument_latitude - moon_mean_anomaly).to_radians())
-        + 0.0005 * f64::sin((2.0 * moon_mean_anomaly + sun_mean_anomaly).to_radians());
-    let delta_t = if t < -11.0 {
+    lunar_correction += 0.0104 * f64::sin(2.0 * moon_argument_latitude.to_radians());
+    lunar_correction -= 0.0051 * f64::sin((sun_mean_anomaly + moon_mean_anomaly).to_radians());
+    lunar_correction -= 0.0074 * f64::sin((sun_mean_anomaly - moon_mean_anomaly).to_radians());
+    lunar_correction +=
+        0.0004 * f64::sin((2.0 * moon_argument_latitude + sun_mean_anomaly).to_radians());
+    lunar_correction -=
+        0.0004 * f64::sin((2.0 * moon_argument_latitude - sun_mean_anomaly).to_radians());
+    lunar_correction -=
+        0.0006 * f64::sin((2.0 * moon_argument_latitude + moon_mean_anomaly).to_radians());
+    lunar_correction +=
+        0.0010 * f64::sin((2.0 * moon_argument_latitude - moon_mean_anomaly).to_radians());
+    lunar_correction +=
+        0.0005 * f64::sin((2.0 * moon_mean_anomaly + sun_mean_anomaly).to_radians());
+    mean_new_moon + lunar_correction - delta_t_aa98(t)
+}
+
+/// ΔT of the AA98 new moon, Terrestrial Time minus Universal Time, in days.
+///
+/// Parameters:
+/// - `t`: Julian centuries from 1900 January 0.5.
+fn delta_t_aa98(t: f64) -> f64 {
+    let t_2 = t * t;
+    let t_3 = t_2 * t;
+    if t < -11.0 {
         0.001 + 0.000839 * t + 0.0002261 * t_2 - 0.00000845 * t_3 - 0.000000081 * t * t_3
     } else {
         -0.000278 + 0.000265 * t + 0.000262 * t_2
-    };
-    mean_new_moon + lunar_correction - delta_t
+    }
+}
+
+/// Estimate ΔT, Terrestrial Time minus Universal Time, in days.
+///
+/// Uses the long-term parabola of Morrison and Stephenson, within a minute of the observed
+/// values since 1800.
+pub(crate) fn delta_t_parabola(julian_day: f64) -> f64 {
+    let year = 2000.0 + (julian_day - 2451544.5) / 365.25;
+    let u = (year - 1820.0) / 100.0;
+    (-20.0 + 32.0 * u * u) / 86400.0
 }
 
 /// Get the first day of month in LichTa Calendar in Julian day.
@@ -199,12 +213,51 @@ fn new_moon_aa98(julian_month_index: JulianMonthIndex) -> f64 {
 /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
 ///
 /// Return: Julian day number
-fn get_new_moon_day(julian_month_index: JulianMonthIndex, timezone: f64) -> f64 {
+pub(crate) fn get_new_moon_day(
+    julian_month_index: JulianMonthIndex,
+    timezone: impl LocalTime,
+) -> f64 {
     let jd = new_moon_aa98(julian_month_index);
+    let timezone = timezone.offset_hours(jd);
+    #[cfg(feature = "high-accuracy")]
+    let jd = crate::refine::refine_new_moon(julian_month_index, jd, timezone);
     (jd + 0.5 + timezone / 24.0).floor()
 }
 
-const SOLAR_LONGITUDE_THRESHOLD: f64 = 9.0;
+/// Get the Julian day of the new moon with index `k`, at local midnight.
+///
+/// Parameters:
+/// - `k`: Index of the new moon, e.g. from [`lunar_month_to_k`].
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: Julian day number of the day the new moon falls on.
+pub fn new_moon_day(k: JulianMonthIndex, timezone: f64) -> f64 {
+    get_new_moon_day(k, timezone)
+}
+
+/// Distance from local midnight, in days, under which the day of a new moon depends on the
+/// model computing it: 30 minutes.
+pub(crate) const MIDNIGHT_WINDOW: f64 = 1.0 / 48.0;
+
+/// Check whether a day is next to a new moon within [`MIDNIGHT_WINDOW`] of local midnight, so
+/// that a more accurate model may start the month a day earlier or later.
+///
+/// Parameters:
+/// - `julian_day`: Julian day number of the day.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+pub(crate) fn is_near_month_boundary(julian_day: i32, timezone: impl LocalTime) -> bool {
+    let julian_month_index = JulianMonthIndex::from_julian_day(julian_day.into());
+    (-1..=1).any(|offset| {
+        let new_moon = new_moon_aa98(julian_month_index.offset(offset));
+        let local = new_moon + 0.5 + timezone.offset_hours(new_moon) / 24.0;
+        let midnight = local.round();
+        // The days ending and starting at that midnight
+        (local - midnight).abs() < MIDNIGHT_WINDOW
+            && (midnight - 1.0..=midnight).contains(&f64::from(julian_day))
+    })
+}
+
+const SOLAR_LONGITUDE_THRESHOLD: i32 = 9;
 
 /// Get the Julian day for the beginning of month 11 in the LichTa calendar for a given year.
 ///
@@ -217,19 +270,65 @@ const SOLAR_LONGITUDE_THRESHOLD: f64 = 9.0;
 /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
 ///
 /// Returns: Julian day number for the start of the 11th lunar month.
-fn get_lunar_month_11(year: i32, timezone: f64) -> f64 {
-    let date =
-        Date::from_calendar_date(year, time::Month::December, 31).expect("Invalid date for year");
-    let julian_day: f64 = date.to_julian_day().into();
+pub(crate) fn get_lunar_month_11(year: i32, timezone: impl LocalTime) -> f64 {
+    get_lunar_month_11_with_lookback(year, 0, timezone)
+}
+
+/// Same as [`get_lunar_month_11`], with the search starting `lookback_days` before
+/// December 31.
+///
+/// The search picks the new moon from the mean lunation, which can be most of a day off the
+/// true one. Up to 7 days, the anchor stays far enough after a month 11 starting as late as
+/// December 22 to find the same month.
+pub(crate) fn get_lunar_month_11_with_lookback(
+    year: i32,
+    lookback_days: u8,
+    timezone: impl LocalTime,
+) -> f64 {
+    let anchor = december_31_julian_day(year) - i32::from(lookback_days);
+    find_lunar_month_11(anchor.into(), timezone)
+}
+
+/// Get the Julian day number of December 31 of a proleptic Gregorian year.
+///
+/// Integer math rather than [`Date`], so that the month 11 lookup cannot fail for the years
+/// just outside the range of [`Date`] that a conversion at its edges may reach.
+fn december_31_julian_day(year: i32) -> i32 {
+    // Years counted from March 4801 BCE, December 31 ends the year starting in March
+    let years = year + 4800;
+    let days_before_december_31 = 306;
+    365 * years + years.div_euclid(4) - years.div_euclid(100)
+        + years.div_euclid(400)
+        + days_before_december_31
+        - 32045
+}
+
+/// Get the start of the month 11 found by searching back from `anchor`.
+///
+/// This is the search behind the month 11 of a year, which anchors it on December 31: the
+/// month 11 is the month starting at the last new moon before the anchor, or the one before
+/// it when the sun is already past 270° at that new moon. Other anchors are useful to study
+/// calendars with a different reference.
+///
+/// Parameters:
+/// - `anchor`: Date to search back from.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: Julian day number for the start of the 11th lunar month.
+pub fn lunar_month_11_from(anchor: Date, timezone: f64) -> f64 {
+    find_lunar_month_11(anchor.to_julian_day().into(), timezone)
+}
+
+/// Same as [`lunar_month_11_from`], from the Julian day of the anchor in any [`LocalTime`].
+fn find_lunar_month_11(julian_day: f64, timezone: impl LocalTime) -> f64 {
     let k = JulianMonthIndex::from_julian_day(julian_day);
     // Calculate the new moon day for the current k value.
     let new_moon_day = get_new_moon_day(k, timezone);
 
     // Determine the solar longitude and adjust for the beginning of lunar month 11.
-    let sun_longitute = (get_sun_longitude(new_moon_day, timezone) / 30.0).trunc();
-    if sun_longitute >= SOLAR_LONGITUDE_THRESHOLD {
+    if solar_segment(get_sun_longitude(new_moon_day, timezone)) >= SOLAR_LONGITUDE_THRESHOLD {
         // If the solar longitude indicates a new lunar month has started, adjust k.
-        get_new_moon_day(k - JulianMonthIndex::new(1), timezone)
+        get_new_moon_day(k.offset(-1), timezone)
     } else {
         new_moon_day
     }
@@ -237,34 +336,247 @@ fn get_lunar_month_11(year: i32, timezone: f64) -> f64 {
 
 const SOLAR_LONGITUDE_SEGMENT: f64 = 30.0; // Each segment of solar longitude for a lunar month
 
+/// Get the 30° segment of a sun longitude, from 0 for [0°, 30°) to 11 for [330°, 360°).
+///
+/// Each segment holds one major solar term. Longitudes outside 0..360 are brought back into
+/// it first, so -15° is in segment 11, and the rounding is always down.
+pub(crate) fn solar_segment(longitude: f64) -> i32 {
+    (longitude.rem_euclid(360.0) / SOLAR_LONGITUDE_SEGMENT).floor() as i32
+}
+
+/// Leap month offset returned when none of the months after month 11 repeats a solar
+/// longitude segment. It is past the last month, so no month is treated as leap.
+pub(crate) const NO_LEAP_MONTH_OFFSET: i32 = 14;
+
 /// Get the leap month offset for a lunar calendar year potentially having 13 months.
 ///
-//// The leap month is determined based on consecutive lunar months having the same solar longitude,
+/// The leap month is determined based on consecutive lunar months having the same solar longitude,
 /// indicating a leap month insertion. The check starts from the given month 11.
 ///
 /// Parameters:
 /// - `first_month_11`: Begin day of month 11 which one of 13 month is leap month.
 /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
 ///
-/// Returns: Index of the leap month after month 11, or 14 if no leap month is found.
-fn get_leap_month_offset(first_month_11: i32, timezone: f64) -> i32 {
-    let a11: f64 = first_month_11.try_into().unwrap();
-    let julian_month_index = JulianMonthIndex::from_julian_day(a11);
-    let mut last_solar_longitude = 0.0;
+/// Returns: Index of the leap month after month 11, or [`NO_LEAP_MONTH_OFFSET`] if no leap
+/// month is found.
+pub(crate) fn get_leap_month_offset(first_month_11: i32, timezone: impl LocalTime) -> i32 {
+    let a11: f64 = first_month_11.into();
+    // Round to the new moon starting month 11, a11 may be a little before the mean new moon
+    let julian_month_index = JulianMonthIndex::from_julian_day(a11 + JULIAN_MOON_CYCLE / 2.0);
+    let mut last_segment = None;
     for i in 1..14 {
-        let day_number = get_new_moon_day(julian_month_index + JulianMonthIndex::new(i), timezone);
-        let solar_longitude =
-            (get_sun_longitude(day_number, timezone) / SOLAR_LONGITUDE_SEGMENT).floor();
-        if solar_longitude == last_solar_longitude {
+        let day_number = get_new_moon_day(julian_month_index.offset(i), timezone);
+        let segment = solar_segment(get_sun_longitude(day_number, timezone));
+        if last_segment == Some(segment) {
             return i - 1;
         }
-        last_solar_longitude = solar_longitude;
+        last_segment = Some(segment);
+    }
+    NO_LEAP_MONTH_OFFSET
+}
+
+/// Convert a Julian day number to a [`Date`], if it is in the supported range.
+pub(crate) fn date_from_julian_day(julian_day: f64) -> Option<Date> {
+    Date::from_julian_day(julian_day as i32).ok()
+}
+
+/// Get the range of Gregorian dates [`convert_date_to_lichta`] supports.
+///
+/// A date needs the month 11s of the years before and after it, so the first and last years
+/// of the model, ±9999, are left out. The `large-dates` feature widens the range of [`Date`],
+/// not this one.
+///
+/// The AA98 formulas for the sun and the moon are fitted to a few centuries around 2000 and
+/// ignore ΔT, the drift of the Earth's rotation, which reaches hours by year 0. New moons
+/// close to midnight, and so the month boundaries, become unreliable a few millennia away
+/// from 2000, and the dates towards ±9999 are only self-consistent, not historical. Further
+/// out the ΔT polynomial grows so fast that months are no longer about 29.5 days long.
+pub fn supported_date_range() -> RangeInclusive<Date> {
+    FIRST_SUPPORTED_DATE..=LAST_SUPPORTED_DATE
+}
+
+/// First and last years the conversion model holds over, checked day by day.
+const FIRST_MODEL_YEAR: i32 = -9999;
+const LAST_MODEL_YEAR: i32 = 9999;
+
+// Checked when compiling rather than with `expect` on every call
+const FIRST_SUPPORTED_DATE: Date = match Date::from_ordinal_date(FIRST_MODEL_YEAR + 1, 1) {
+    Ok(date) => date,
+    Err(_) => panic!("Invalid date for year"),
+};
+const LAST_SUPPORTED_DATE: Date =
+    match Date::from_calendar_date(LAST_MODEL_YEAR - 1, time::Month::December, 31) {
+        Ok(date) => date,
+        Err(_) => panic!("Invalid date for year"),
+    };
+
+/// Largest timezone offset, in hours either way, [`try_convert_date_to_lichta`] accepts.
+const MAX_TIMEZONE_HOURS: f64 = 24.0;
+
+/// Error of the AA98 new moon from the periodic terms it leaves out, in days: 5 minutes.
+const SERIES_TRUNCATION_ERROR: f64 = 5.0 / 1440.0;
+
+/// Get the worst-case error of the conversion of a date, in days: 0 or 1.
+///
+/// The AA98 new moon is off by a few minutes from its truncated series, plus the error of its
+/// ΔT, the drift of the Earth's rotation. ΔT is only known from observations, the spread
+/// between the AA98 fit and the long-term parabola of Morrison and Stephenson is taken as its
+/// error. It grows with the square of the distance from 1900, from seconds to hours.
+///
+/// While the total stays under 30 minutes, only the days next to a new moon that close to
+/// midnight can move, and [`LichTaDetails::is_near_month_boundary`] flags them: this returns
+/// 0. Beyond, about before the year -900 and after 3000, any month boundary may be a day off
+/// and this returns 1.
+///
+/// [`LichTaDetails::is_near_month_boundary`]: crate::LichTaDetails::is_near_month_boundary
+pub fn max_error_days(date: Date) -> u8 {
+    let julian_day = f64::from(date.to_julian_day());
+    let t = (julian_day - JULIAN_DAY_NOON_JAN_1_1900) / (JULIAN_MOON_CYCLE * 1236.85);
+    let delta_t_error = (delta_t_aa98(t) - delta_t_parabola(julian_day)).abs();
+    u8::from(SERIES_TRUNCATION_ERROR + delta_t_error >= MIDNIGHT_WINDOW)
+}
+
+/// Check that every month of `year` can be converted, including the month 11 bounding the
+/// months next to it.
+fn is_supported_lunar_year(year: i32) -> bool {
+    (FIRST_MODEL_YEAR + 2..=LAST_MODEL_YEAR - 2).contains(&year)
+}
+
+/// Get the **Julian Month** index of a month in the LichTa calendar.
+///
+/// This is the inverse of [`convert_date_to_lichta`]: the month is estimated from the month 11
+/// of the previous year, then the candidate new moons are converted until one of them starts
+/// the requested month. Searching keeps both directions of the conversion consistent.
+///
+/// Parameters:
+/// - `month`: Lunar month from 1 to 12.
+/// - `year`: Lunar year.
+/// - `is_leap_month`: Whether the month is the leap month of the year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: Index of the month, or an error if the month does not exist in that year.
+pub(crate) fn get_lunar_month_index(
+    month: i32,
+    year: i32,
+    is_leap_month: bool,
+    timezone: impl LocalTime,
+) -> Result<JulianMonthIndex, LichTaError> {
+    get_lunar_month_index_with(
+        month,
+        year,
+        is_leap_month,
+        timezone,
+        &mut Uncached(timezone),
+    )
+}
+
+/// Get the index `k` of the new moon starting a month in the LichTa calendar.
+///
+/// ```
+/// use lich_ta::{lunar_month_to_k, new_moon_day, Date};
+///
+/// let k = lunar_month_to_k(1, 2025, false, 7.0).unwrap();
+/// let tet = Date::from_calendar_date(2025, time::Month::January, 29).unwrap();
+/// assert_eq!(new_moon_day(k, 7.0), f64::from(tet.to_julian_day()));
+/// ```
+///
+/// Parameters:
+/// - `month`: Lunar month from 1 to 12.
+/// - `year`: Lunar year.
+/// - `leap`: Whether the month is the leap month of the year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: Index of the month, or `None` if the month does not exist in that year.
+pub fn lunar_month_to_k(
+    month: i32,
+    year: i32,
+    leap: bool,
+    timezone: f64,
+) -> Option<JulianMonthIndex> {
+    get_lunar_month_index(month, year, leap, timezone).ok()
+}
+
+/// Same as [`get_lunar_month_index`], taking the month 11 and leap month offsets from
+/// `year_values`.
+pub(crate) fn get_lunar_month_index_with(
+    month: i32,
+    year: i32,
+    is_leap_month: bool,
+    timezone: impl LocalTime,
+    year_values: &mut impl YearValues,
+) -> Result<JulianMonthIndex, LichTaError> {
+    if !(1..=12).contains(&month) {
+        return Err(LichTaError::InvalidMonth(month));
+    }
+    if !is_supported_lunar_year(year) {
+        return Err(LichTaError::DateOutOfRange);
+    }
+    // Month 1 is two months after the month 11 of the previous year, the leap month and
+    // rounding may move the month one index either way.
+    let first_month_11 = f64::from(year_values.lunar_month_11(year - 1));
+    let estimate = JulianMonthIndex::from_julian_day(first_month_11 + JULIAN_MOON_CYCLE / 2.0)
+        .offset(month + 1);
+    for offset in -1..=2 {
+        let julian_month_index = estimate.offset(offset);
+        let month_start = get_new_moon_day(julian_month_index, timezone);
+        let Some(date) = date_from_julian_day(month_start) else {
+            continue;
+        };
+        let conversion = convert_date_to_lichta_with(date, timezone, year_values);
+        let (_, lunar_month, lunar_year, lunar_leap) = conversion.to_tuple();
+        if (lunar_month, lunar_year, lunar_leap == 1) == (month, year, is_leap_month) {
+            return Ok(julian_month_index);
+        }
+    }
+    if is_leap_month {
+        Err(LichTaError::NoSuchLeapMonth { month, year })
+    } else {
+        Err(LichTaError::DateOutOfRange)
     }
-    14
 }
 
-fn calculate_month_between_julian_days(julian_day_1: f64, julian_day_2: f64) -> i32 {
-    ((julian_day_1 - julian_day_2) / 29.0) as i32
+/// Get the first day and the length of a month in the LichTa calendar.
+///
+/// Parameters:
+/// - `month`: Lunar month from 1 to 12.
+/// - `year`: Lunar year.
+/// - `is_leap_month`: Whether the month is the leap month of the year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: (julian_day: f64, length: i32), length is 29 or 30.
+pub(crate) fn get_lunar_month_span(
+    month: i32,
+    year: i32,
+    is_leap_month: bool,
+    timezone: impl LocalTime,
+) -> Result<(f64, i32), LichTaError> {
+    get_lunar_month_span_with(
+        month,
+        year,
+        is_leap_month,
+        timezone,
+        &mut Uncached(timezone),
+    )
+}
+
+/// Same as [`get_lunar_month_span`], taking the month 11 and leap month offsets from
+/// `year_values`.
+pub(crate) fn get_lunar_month_span_with(
+    month: i32,
+    year: i32,
+    is_leap_month: bool,
+    timezone: impl LocalTime,
+    year_values: &mut impl YearValues,
+) -> Result<(f64, i32), LichTaError> {
+    let julian_month_index =
+        get_lunar_month_index_with(month, year, is_leap_month, timezone, year_values)?;
+    let month_start = get_new_moon_day(julian_month_index, timezone);
+    let next_month_start = get_new_moon_day(julian_month_index.offset(1), timezone);
+    Ok((month_start, (next_month_start - month_start) as i32))
+}
+
+fn calculate_month_between_julian_days(julian_day_1: i32, julian_day_2: i32) -> i32 {
+    (julian_day_1 - julian_day_2) / 29
 }
 
 /// Convert Gregorian day to Lichta day
@@ -272,36 +584,240 @@ fn calculate_month_between_julian_days(julian_day_1: f64, julian_day_2: f64) ->
 /// The leap month is determined based on consecutive lunar months having the same solar longitude,
 /// indicating a leap month insertion. The check starts from the given month 11.
 ///
+/// Years use the astronomical numbering of [`Date`]: year 0 is 1 BCE, year -1 is 2 BCE and so
+/// on, and the lunar year follows the same numbering.
+///
 /// Parameters:
-/// - `first_month_11`: Begin day of month 11 which one of 13 months is leap month.
+/// - `date`: Gregorian date to convert.
 /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
 ///
-/// Returns: (day: i32, month: i32, year: i32, leap: bool)
+/// Returns: (day: i32, month: i32, year: i32, leap: i32), leap is 1 for a leap month else 0.
+///
+/// # Panics
+///
+/// May panic for a date outside [`supported_date_range`], whose month 11s fall out of the
+/// range of [`Date`]. Use [`try_convert_date_to_lichta`] for such dates.
 pub fn convert_date_to_lichta(date: Date, timezone: f64) -> (i32, i32, i32, i32) {
-    let julian_day: f64 = date.to_julian_day().into();
-    let julian_month_index = JulianMonthIndex::from_julian_day(julian_day);
+    convert_date_to_lichta_with(date, timezone, &mut Uncached(timezone)).to_tuple()
+}
+
+/// Convert Gregorian day to Lichta day, checking the date first.
+///
+/// The conversion looks up the month 11s of the years before and after the date, which do not
+/// exist in the first and last years of [`Date`].
+///
+/// Parameters:
+/// - `date`: Gregorian date to convert.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The same tuple as [`convert_date_to_lichta`],
+/// [`LichTaError::InvalidTimezone`] for a timezone that is not a number of hours between -24
+/// and 24, or [`LichTaError::DateOutOfRange`] for a date outside [`supported_date_range`].
+///
+/// The date check keeps the conversion within the years its model holds over, where the debug
+/// assertions on the months hold, and the timezone check keeps the day math from overflowing.
+pub fn try_convert_date_to_lichta(
+    date: Date,
+    timezone: f64,
+) -> Result<(i32, i32, i32, i32), LichTaError> {
+    // Also rejects NaN
+    if !(-MAX_TIMEZONE_HOURS..=MAX_TIMEZONE_HOURS).contains(&timezone) {
+        return Err(LichTaError::InvalidTimezone);
+    }
+    if !supported_date_range().contains(&date) {
+        return Err(LichTaError::DateOutOfRange);
+    }
+    Ok(convert_date_to_lichta(date, timezone))
+}
+
+/// Convert an instant to the LichTa calendar, in the timezone of its offset.
+///
+/// Lunar days begin at local midnight, like Gregorian ones. The local date of `datetime` is
+/// converted, so an instant at 23:30 belongs to the lunar day of its local date even when it
+/// is already the next day in UTC. Use [`OffsetDateTime::to_offset`] first to convert the
+/// instant for another timezone.
+///
+/// Parameters:
+/// - `datetime`: Instant with its local UTC offset.
+///
+/// [`OffsetDateTime::to_offset`]: time::OffsetDateTime::to_offset
+pub fn convert_datetime_to_lichta(datetime: time::OffsetDateTime) -> NgayTa {
+    let timezone = f64::from(datetime.offset().whole_seconds()) / 3600.0;
+    NgayTa::from_date(datetime.date(), timezone)
+}
+
+/// Get the Gregorian date of a day of a LichTa month.
+///
+/// Parameters:
+/// - `year`: Lunar year.
+/// - `month`: Lunar month, from 1 to 12.
+/// - `leap`: Whether the month is the leap month of the year.
+/// - `day`: Day of the month, from 1 to 29 or 30.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns an error if the month is not in `1..=12`, if the year has no such leap month, or
+/// if the day does not exist in the month.
+pub fn gregorian_of_lunar_day(
+    year: i32,
+    month: i32,
+    leap: bool,
+    day: i32,
+    timezone: f64,
+) -> Result<Date, LichTaError> {
+    gregorian_of_lunar_day_with(year, month, leap, day, timezone, &mut Uncached(timezone))
+}
+
+/// Same as [`gregorian_of_lunar_day`], taking the month 11 and leap month offsets from
+/// `year_values`.
+pub(crate) fn gregorian_of_lunar_day_with(
+    year: i32,
+    month: i32,
+    leap: bool,
+    day: i32,
+    timezone: impl LocalTime,
+    year_values: &mut impl YearValues,
+) -> Result<Date, LichTaError> {
+    let (month_start, month_length) =
+        get_lunar_month_span_with(month, year, leap, timezone, year_values)?;
+    if !(1..=month_length).contains(&day) {
+        return Err(LichTaError::DayOutOfRange { day, month_length });
+    }
+    date_from_julian_day(month_start + f64::from(day - 1)).ok_or(LichTaError::DateOutOfRange)
+}
 
-    let mut month_start = get_new_moon_day(julian_month_index + JulianMonthIndex::new(1), timezone);
-    if month_start > julian_day {
-        month_start = get_new_moon_day(julian_month_index, 7.0);
+/// Get the first day of the lunar month containing a day, as a whole Julian day.
+pub(crate) fn get_month_start(julian_day: i32, timezone: impl LocalTime) -> i32 {
+    let julian_month_index = JulianMonthIndex::from_julian_day(julian_day.into());
+    let month_start = get_new_moon_day(julian_month_index.offset(1), timezone) as i32;
+    if month_start <= julian_day {
+        return month_start;
+    }
+    let month_start = get_new_moon_day(julian_month_index, timezone) as i32;
+    if month_start <= julian_day {
+        return month_start;
     }
+    // The index is from the mean lunation, the true new moon may be most of a day after it,
+    // and an eastern timezone moves its day later still
+    get_new_moon_day(julian_month_index.offset(-1), timezone) as i32
+}
 
-    let mut first_month_11 = get_lunar_month_11(date.year(), timezone);
-    let mut last_month_11 = first_month_11;
-    if first_month_11 >= month_start {
-        first_month_11 = get_lunar_month_11(date.year() - 1, timezone);
+/// Get the month 11s used to number the lunar month starting on `month_start`.
+///
+/// Returns: (first_month_11, last_month_11, last_year), the month 11s as whole Julian days,
+/// the first one is before `month_start` and the last one is at or after it. `last_year` is
+/// the year whose month 11 is the last one, `year` or `year + 1` for a month starting after
+/// the month 11 of `year`.
+fn get_bounding_month_11s(
+    year: i32,
+    month_start: i32,
+    year_values: &mut impl YearValues,
+) -> (i32, i32, i32) {
+    let month_11 = year_values.lunar_month_11(year);
+    if month_11 >= month_start {
+        (year_values.lunar_month_11(year - 1), month_11, year)
     } else {
-        last_month_11 = get_lunar_month_11(date.year() + 1, timezone);
+        (month_11, year_values.lunar_month_11(year + 1), year + 1)
+    }
+}
+
+/// Get the Gregorian dates of the two month 11s bounding a date.
+///
+/// These are the month 11s the conversion numbers the months from: the first one starts
+/// before the lunar month of `date` and the last one starts at or after it. When they are
+/// more than 365 days apart the lunar year between them has 13 months, one of which is a
+/// leap month.
+///
+/// Parameters:
+/// - `date`: Gregorian date.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Panics if a month 11 is outside the range supported by [`Date`].
+pub fn bounding_month_11s(date: Date, timezone: f64) -> (Date, Date) {
+    let month_start = get_month_start(date.to_julian_day(), timezone);
+    let (first_month_11, last_month_11, _) =
+        get_bounding_month_11s(date.year(), month_start, &mut Uncached(timezone));
+    let to_date =
+        |julian_day: i32| Date::from_julian_day(julian_day).expect("Invalid date for month 11");
+    (to_date(first_month_11), to_date(last_month_11))
+}
+
+/// Values of the conversion that only depend on the year, which callers may cache.
+pub(crate) trait YearValues {
+    /// Same as [`get_lunar_month_11`], as a whole Julian day.
+    fn lunar_month_11(&mut self, year: i32) -> i32;
+    /// Same as [`get_leap_month_offset`].
+    fn leap_month_offset(&mut self, first_month_11: i32) -> i32;
+}
+
+/// [`YearValues`] computed again on every call, for the given timezone.
+pub(crate) struct Uncached<T>(pub T);
+
+impl<T: LocalTime> YearValues for Uncached<T> {
+    fn lunar_month_11(&mut self, year: i32) -> i32 {
+        get_lunar_month_11(year, self.0) as i32
+    }
+    fn leap_month_offset(&mut self, first_month_11: i32) -> i32 {
+        get_leap_month_offset(first_month_11, self.0)
     }
-    let lunar_day = (julian_day - month_start + 1.0) as i32;
+}
+
+/// Result of a conversion, with the intermediate values of the algorithm.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Conversion {
+    pub lunar_day: i32,
+    pub lunar_month: i32,
+    pub lunar_year: i32,
+    pub lunar_leap: i32,
+    /// Julian day of the first day of the lunar month.
+    pub month_start: i32,
+    /// Number of months from the month 11 before the date to the lunar month.
+    pub month_difference: i32,
+}
+
+impl Conversion {
+    pub fn to_tuple(self) -> (i32, i32, i32, i32) {
+        (
+            self.lunar_day,
+            self.lunar_month,
+            self.lunar_year,
+            self.lunar_leap,
+        )
+    }
+}
+
+/// Convert Gregorian day to Lichta day, looking up the values depending on the year in
+/// `year_values`.
+///
+/// Parameters:
+/// - `date`: Gregorian date to convert.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+/// - `year_values`: Month 11 and leap month offsets for `timezone`, e.g. from a cache.
+///
+/// Returns: The converted date along with the intermediate values.
+pub(crate) fn convert_date_to_lichta_with(
+    date: Date,
+    timezone: impl LocalTime,
+    year_values: &mut impl YearValues,
+) -> Conversion {
+    // Julian day numbers are whole days, the rest of the conversion is integer math
+    let julian_day = date.to_julian_day();
+    let month_start = get_month_start(julian_day, timezone);
+    let (first_month_11, last_month_11, last_year) =
+        get_bounding_month_11s(date.year(), month_start, year_values);
+    let lunar_day = julian_day - month_start + 1;
 
     let month_difference = calculate_month_between_julian_days(month_start, first_month_11);
 
     let mut lunar_leap = 0;
 
     let mut lunar_month = month_difference + 11;
-    if last_month_11 - first_month_11 > 365.0 {
-        let leap_month_index = get_leap_month_offset(first_month_11 as i32, timezone);
+    if last_month_11 - first_month_11 > 365 {
+        let leap_month_index = year_values.leap_month_offset(first_month_11);
+        // A 13-month year always has a month without a major solar term
+        debug_assert_ne!(
+            leap_month_index, NO_LEAP_MONTH_OFFSET,
+            "No leap month found in a 13-month year"
+        );
         if month_difference >= leap_month_index {
             lunar_month = month_difference + 10;
             if month_difference == leap_month_index {
@@ -311,18 +827,30 @@ pub fn convert_date_to_lichta(date: Date, timezone: f64) -> (i32, i32, i32, i32)
         }
     }
 
-    // Normalize the lunar month to ensure it falls within the typical 1-12 range
-    if lunar_month > 12 {
-        lunar_month -= 12;
-    }
+    // A lunar year has at most 13 months, so the month is 10 to 24 here, the month 11 of the
+    // last month 11 included. Bring it back into 1..=12 whatever the difference.
+    debug_assert!(
+        (0..=13).contains(&month_difference),
+        "Month difference out of range: {month_difference}"
+    );
+    lunar_month = (lunar_month - 1).rem_euclid(12) + 1;
 
-    // Adjust the lunar year based on the lunar month
-    let mut lunar_year = date.year();
+    // The months before the last month 11 end the lunar year of that month 11, except for
+    // the month 11 and 12 right after the first month 11, which end the year before. Taking
+    // the year of the date instead mislabels a month 12 starting in December.
+    let mut lunar_year = last_year;
     if lunar_month >= 11 && month_difference < 4 {
         lunar_year -= 1;
     }
 
-    (lunar_day, lunar_month, lunar_year, lunar_leap)
+    Conversion {
+        lunar_day,
+        lunar_month,
+        lunar_year,
+        lunar_leap,
+        month_start,
+        month_difference,
+    }
 }
 
 #[cfg(test)]
@@ -336,10 +864,48 @@ mod tests {
         assert_eq!(get_sun_longitude(jdn, 7.0), 254.13250183229925);
     }
 
+    #[test]
+    fn test_solar_segment() {
+        assert_eq!(solar_segment(0.0), 0);
+        assert_eq!(solar_segment(29.999), 0);
+        assert_eq!(solar_segment(270.0), 9);
+        assert_eq!(solar_segment(359.9), 11);
+        assert_eq!(solar_segment(360.0), 0);
+        // Negative longitudes round down, where truncating would give segment 0
+        assert_eq!(solar_segment(-0.5), 11);
+        assert_eq!(solar_segment(-15.0), 11);
+        assert_eq!(solar_segment(-90.0), 9);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Julian day must be finite")]
+    fn test_sun_longitude_nan_timezone() {
+        get_sun_longitude(2451520_f64, f64::NAN);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Julian day must be finite")]
+    fn test_convert_nan_timezone() {
+        let date = Date::from_calendar_date(2024, time::Month::May, 24).unwrap();
+        convert_date_to_lichta(date, f64::NAN);
+    }
+
     #[test]
     fn test_new_moon_aa98() {
-        let k = JulianMonthIndex::new(1533);
-        assert_eq!(new_moon_aa98(k), 2_460_291.49468915);
+        let k = JulianMonthIndex::from(1533);
+        assert_eq!(new_moon_aa98(k), 2_460_291.480190389);
+    }
+
+    #[test]
+    fn test_new_moon_aa98_term_signs() {
+        // The terms in 2M' and 2F + M add to the new moon and the one in 2F + M' subtracts,
+        // the other signs started the month 10 of 2015 on 2015-11-11, a day early
+        let day_before = Date::from_calendar_date(2015, time::Month::November, 11).unwrap();
+        assert_eq!(convert_date_to_lichta(day_before, 7.0), (30, 9, 2015, 0));
+        let month_start = day_before.next_day().unwrap();
+        assert_eq!(convert_date_to_lichta(month_start, 7.0), (1, 10, 2015, 0));
     }
 
     #[test]
@@ -349,7 +915,23 @@ mod tests {
     #[test]
     fn test_get_leap_month_offset() {
         let a11 = get_lunar_month_11(2022, 7.0) as i32;
-        assert_eq!(get_leap_month_offset(a11, 7.0), 3);
+        assert_eq!(get_leap_month_offset(a11, 7.0), 4);
+        // Month 11 of 1902 starts a day before its mean new moon, counting from the month
+        // before it would put the leap month one month late
+        let a11 = get_lunar_month_11(1902, 7.0) as i32;
+        assert_eq!(get_leap_month_offset(a11, 7.0), 7);
+    }
+
+    #[test]
+    fn test_leap_month_after_early_month_11() {
+        // Month 11 of 1902 starts before its mean new moon, flooring it to a new moon index
+        // counted from the month 10 and made the leap month 5 of 1903 a leap month 6
+        assert_eq!(crate::LunarYear::new(1903, 7.0).leap_month(), Some(5));
+        let leap_month_start = gregorian_of_lunar_day(1903, 5, true, 1, 7.0).unwrap();
+        assert_eq!(
+            convert_date_to_lichta(leap_month_start, 7.0),
+            (1, 5, 1903, 1)
+        );
     }
 
     #[test]
@@ -362,4 +944,479 @@ mod tests {
         let lichta = convert_date_to_lichta(date, 7.0);
         assert_eq!(lichta, (24, 4, 2022, 0));
     }
+
+    #[test]
+    fn test_month_start_after_the_mean_new_moon() {
+        // The new moon of 1877-04-14 in UTC+7 is more than a lunation after the mean one
+        // indexing 1877-04-13, the day before it ends month 2 rather than being a day 0
+        let date = Date::from_calendar_date(1877, time::Month::April, 13).unwrap();
+        assert_eq!(convert_date_to_lichta(date, 7.0), (30, 2, 1877, 0));
+        let next_day = date.next_day().unwrap();
+        assert_eq!(convert_date_to_lichta(next_day, 7.0), (1, 3, 1877, 0));
+
+        let mut date = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+        while date.year() < 2100 {
+            let (day, _, _, _) = convert_date_to_lichta(date, 14.0);
+            assert!((1..=30).contains(&day), "{date}: day {day}");
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_convert_year_zero_and_negative() {
+        let date = Date::from_calendar_date(0, time::Month::January, 23).unwrap();
+        assert_eq!(convert_date_to_lichta(date, 7.0), (1, 1, 0, 0));
+        let date = Date::from_calendar_date(-1, time::Month::February, 3).unwrap();
+        assert_eq!(convert_date_to_lichta(date, 7.0), (1, 1, -1, 0));
+
+        let mut date = Date::from_calendar_date(-2, time::Month::January, 1).unwrap();
+        while date.year() < 2 {
+            let (day, month, year, _) = convert_date_to_lichta(date, 7.0);
+            assert!((1..=30).contains(&day), "{date}: day {day}");
+            assert!((1..=12).contains(&month), "{date}: month {month}");
+            assert!(year == date.year() || year == date.year() - 1);
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_get_lunar_month_span() {
+        let (month_start, length) = get_lunar_month_span(4, 2024, false, 7.0).unwrap();
+        let date = Date::from_julian_day(month_start as i32).unwrap();
+        assert_eq!(
+            date,
+            Date::from_calendar_date(2024, time::Month::May, 8).unwrap()
+        );
+        assert_eq!(length, 29);
+
+        let (month_start, length) = get_lunar_month_span(6, 2025, true, 7.0).unwrap();
+        let date = Date::from_julian_day(month_start as i32).unwrap();
+        assert_eq!(
+            date,
+            Date::from_calendar_date(2025, time::Month::July, 25).unwrap()
+        );
+        assert_eq!(length, 29);
+
+        assert_eq!(
+            get_lunar_month_span(5, 2025, true, 7.0),
+            Err(LichTaError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            })
+        );
+        assert_eq!(
+            get_lunar_month_span(13, 2025, false, 7.0),
+            Err(LichTaError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_lunar_month_to_k() {
+        for (month, year, leap) in [(1, 2025, false), (6, 2025, true), (11, 2024, false)] {
+            let k = lunar_month_to_k(month, year, leap, 7.0).unwrap();
+            let (month_start, _) = get_lunar_month_span(month, year, leap, 7.0).unwrap();
+            assert_eq!(new_moon_day(k, 7.0), month_start);
+        }
+        // Months follow each other, the leap month between 6 and 7
+        let k = lunar_month_to_k(6, 2025, false, 7.0).unwrap();
+        assert_eq!(lunar_month_to_k(6, 2025, true, 7.0), Some(k.offset(1)));
+        assert_eq!(lunar_month_to_k(7, 2025, false, 7.0), Some(k.offset(2)));
+
+        assert_eq!(lunar_month_to_k(5, 2025, true, 7.0), None);
+        assert_eq!(lunar_month_to_k(0, 2025, false, 7.0), None);
+        assert_eq!(lunar_month_to_k(13, 2025, false, 7.0), None);
+    }
+
+    #[test]
+    fn test_gregorian_of_lunar_day() {
+        // Month 8 of 2025 has 29 days, month 7 has 30
+        let date = |month, day| Date::from_calendar_date(2025, month, day).unwrap();
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 8, false, 1, 7.0),
+            Ok(date(time::Month::September, 22))
+        );
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 8, false, 15, 7.0),
+            Ok(date(time::Month::October, 6))
+        );
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 8, false, 29, 7.0),
+            Ok(date(time::Month::October, 20))
+        );
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 8, false, 30, 7.0),
+            Err(LichTaError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 7, false, 30, 7.0),
+            Ok(date(time::Month::September, 21))
+        );
+        assert_eq!(
+            gregorian_of_lunar_day(2025, 8, false, 0, 7.0),
+            Err(LichTaError::DayOutOfRange {
+                day: 0,
+                month_length: 29
+            })
+        );
+    }
+
+    #[test]
+    fn test_bounding_month_11s() {
+        let date = |year, month, day| Date::from_calendar_date(year, month, day).unwrap();
+        // 2025 has a leap month, so its month 11s are 13 months apart
+        let (first, last) = bounding_month_11s(date(2025, time::Month::May, 1), 7.0);
+        assert_eq!(first, date(2024, time::Month::December, 1));
+        assert_eq!(last, date(2025, time::Month::December, 20));
+        assert!((last - first).whole_days() > 365);
+
+        let (first, last) = bounding_month_11s(date(2024, time::Month::May, 1), 7.0);
+        assert_eq!(last, date(2024, time::Month::December, 1));
+        assert!((last - first).whole_days() < 365);
+
+        // A date in month 11 counts its own month 11 as the last one
+        let (_, last) = bounding_month_11s(date(2025, time::Month::December, 25), 7.0);
+        assert_eq!(last, date(2025, time::Month::December, 20));
+    }
+
+    #[test]
+    fn test_lunar_year_around_new_year() {
+        let date = |year, month, day| Date::from_calendar_date(year, month, day).unwrap();
+        let cases = [
+            // Month 12 starting in December belongs to the year of its month 11
+            (date(2024, time::Month::December, 31), (1, 12, 2024, 0)),
+            (date(2022, time::Month::December, 23), (1, 12, 2022, 0)),
+            (date(1984, time::Month::December, 22), (1, 12, 1984, 0)),
+            (date(2025, time::Month::January, 28), (29, 12, 2024, 0)),
+            (date(1985, time::Month::January, 20), (30, 12, 1984, 0)),
+            (date(2023, time::Month::December, 31), (19, 11, 2023, 0)),
+            (date(2025, time::Month::December, 31), (12, 11, 2025, 0)),
+            (date(2026, time::Month::January, 19), (1, 12, 2025, 0)),
+            // 2020 has a leap month 4 before its month 11
+            (date(2020, time::Month::December, 31), (18, 11, 2020, 0)),
+            (date(2021, time::Month::January, 13), (1, 12, 2020, 0)),
+            // 2033 has a leap month 11, ending in January
+            (date(2033, time::Month::December, 22), (1, 11, 2033, 1)),
+            (date(2033, time::Month::December, 31), (10, 11, 2033, 1)),
+            (date(2034, time::Month::January, 20), (1, 12, 2033, 0)),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(convert_date_to_lichta(date, 7.0), expected, "{date}");
+            let (day, month, year, leap) = expected;
+            assert_eq!(
+                gregorian_of_lunar_day(year, month, leap == 1, day, 7.0),
+                Ok(date)
+            );
+        }
+    }
+
+    /// Published Tết dates and leap months of the Vietnamese calendar, UTC+7, for the lunar
+    /// years 2015 to 2026. The sources are in the header of the file.
+    const TET_2015_2025: &str = include_str!("testdata/tet_2015_2025.csv");
+
+    /// Parse a `lunar_year,tet,leap_month` line of [`TET_2015_2025`].
+    fn parse_tet_line(line: &str) -> (i32, Date, Option<i32>) {
+        let mut fields = line.split(',');
+        let mut field = || fields.next().expect("Missing field in Tết line");
+        let lunar_year = field().parse().unwrap();
+        let mut gregorian = field().split('-');
+        let mut part = || gregorian.next().expect("Missing part in Tết date");
+        let (year, month, day) = (
+            part().parse().unwrap(),
+            part().parse::<u8>(),
+            part().parse(),
+        );
+        let month = time::Month::try_from(month.unwrap()).unwrap();
+        let tet = Date::from_calendar_date(year, month, day.unwrap()).unwrap();
+        let leap_month = field().parse().unwrap();
+        (lunar_year, tet, (leap_month != 0).then_some(leap_month))
+    }
+
+    #[test]
+    fn test_almanac_2015_2025() {
+        let mut years = TET_2015_2025
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .skip(1)
+            .map(parse_tet_line)
+            .peekable();
+        let mut checked = 0;
+        while let (Some((lunar_year, tet, leap_month)), Some(&(_, next_tet, _))) =
+            (years.next(), years.peek())
+        {
+            assert_eq!(crate::tet_date(lunar_year, 7.0), tet);
+            assert_eq!(
+                crate::LunarYear::new(lunar_year, 7.0).leap_month(),
+                leap_month,
+                "{lunar_year}"
+            );
+            // Every day from Tết to the next one is in the lunar year, month 1 starting it
+            assert_eq!(convert_date_to_lichta(tet, 7.0), (1, 1, lunar_year, 0));
+            let mut date = tet;
+            while date < next_tet {
+                let (_, _, year, leap) = convert_date_to_lichta(date, 7.0);
+                assert_eq!(year, lunar_year, "{date}");
+                assert!(leap == 0 || leap_month.is_some(), "{date}");
+                date = date.next_day().unwrap();
+            }
+            checked += 1;
+        }
+        assert_eq!(checked, 11);
+    }
+
+    #[test]
+    fn test_epoch_1900() {
+        // Indices count from 1900-01-01 13:50 UTC, rounding down before it to negative ones
+        assert_eq!(JulianMonthIndex::from_julian_day(2415021.0).value(), -1);
+        assert_eq!(JulianMonthIndex::from_julian_day(2415021.5).value(), 0);
+        assert_eq!(JulianMonthIndex::from_julian_day(2415020.0).value(), -1);
+        assert_eq!(
+            JulianMonthIndex::from_julian_day(2415021.0 - 40.0).value(),
+            -2
+        );
+        let k = lunar_month_to_k(12, 1899, false, 7.0).unwrap();
+        assert_eq!(k.value(), 0);
+        assert_eq!(lunar_month_to_k(11, 1899, false, 7.0).unwrap().value(), -1);
+
+        // Month 11 of 1899 starts on 1899-12-03, month 12 on 1900-01-01, month 1 of 1900 on
+        // 1900-01-31 and month 2 on 1900-03-01
+        let month_starts = [
+            ((1899, time::Month::December, 3), (11, 1899)),
+            ((1900, time::Month::January, 1), (12, 1899)),
+            ((1900, time::Month::January, 31), (1, 1900)),
+            ((1900, time::Month::March, 1), (2, 1900)),
+        ];
+        for ((year, month, day), (lunar_month, lunar_year)) in month_starts {
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            assert_eq!(
+                convert_date_to_lichta(date, 7.0),
+                (1, lunar_month, lunar_year, 0),
+                "{date}"
+            );
+            let previous = convert_date_to_lichta(date.previous_day().unwrap(), 7.0);
+            assert_ne!(
+                (previous.1, previous.2),
+                (lunar_month, lunar_year),
+                "{date}"
+            );
+            assert_eq!(
+                gregorian_of_lunar_day(lunar_year, lunar_month, false, 1, 7.0),
+                Ok(date)
+            );
+        }
+        let date = Date::from_calendar_date(1899, time::Month::December, 2).unwrap();
+        assert_eq!(convert_date_to_lichta(date, 7.0), (30, 10, 1899, 0));
+        let date = Date::from_calendar_date(1899, time::Month::December, 31).unwrap();
+        assert_eq!(convert_date_to_lichta(date, 7.0), (29, 11, 1899, 0));
+        assert_eq!(
+            NgayTa::default().to_date(7.0),
+            Ok(Date::from_calendar_date(1900, time::Month::January, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_first_days_of_january() {
+        // January 1 opening a month 12, still in a month 11, and in a leap month 11
+        let cases = [
+            (1900, (1, 12, 1899, 0)),
+            (1985, (11, 12, 1984, 0)),
+            (2001, (7, 12, 2000, 0)),
+            (2024, (20, 11, 2023, 0)),
+            (2025, (2, 12, 2024, 0)),
+            (2034, (11, 11, 2033, 1)),
+            (2050, (8, 12, 2049, 0)),
+        ];
+        for (year, (day, month, lunar_year, leap)) in cases {
+            for offset in 0..5 {
+                let date =
+                    Date::from_calendar_date(year, time::Month::January, 1 + offset).unwrap();
+                let expected = (day + i32::from(offset), month, lunar_year, leap);
+                assert_eq!(convert_date_to_lichta(date, 7.0), expected, "{date}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_month_11_lookback() {
+        for year in 1800..2200 {
+            let month_11 = get_lunar_month_11(year, 7.0);
+            for lookback in 1..=7 {
+                assert_eq!(
+                    get_lunar_month_11_with_lookback(year, lookback, 7.0),
+                    month_11,
+                    "{year}: {lookback}"
+                );
+            }
+        }
+        // December 1, 2025 is before the month 11 starting on December 20
+        assert_ne!(
+            get_lunar_month_11_with_lookback(2025, 30, 7.0),
+            get_lunar_month_11(2025, 7.0)
+        );
+    }
+
+    #[test]
+    fn test_month_in_range_through_leap_years() {
+        // Leap months 2, 4, 6 and 11, and the years around them
+        for year in [2022, 2023, 2025, 2020, 2033] {
+            let mut date = Date::from_calendar_date(year, time::Month::January, 1).unwrap();
+            while date.year() == year {
+                let details = crate::LichTaDetails::from_date(date, 7.0);
+                let month = details.ngay_ta().month();
+                assert!((1..=12).contains(&month), "{date}: month {month}");
+                assert!((0..=13).contains(&details.month_difference()), "{date}");
+                date = date.next_day().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_error_days() {
+        let first_day = |year| Date::from_ordinal_date(year, 1).unwrap();
+        for year in [-800, 0, 1000, 1900, 2025, 2500, 2900] {
+            assert_eq!(max_error_days(first_day(year)), 0, "{year}");
+        }
+        for year in [-9998, -3000, -1000, 3100, 5000, 9999] {
+            assert_eq!(max_error_days(first_day(year)), 1, "{year}");
+        }
+    }
+
+    #[test]
+    fn test_supported_date_range() {
+        let range = supported_date_range();
+        for date in [*range.start(), *range.end()] {
+            let (day, month, _, _) = convert_date_to_lichta(date, 7.0);
+            assert!((1..=30).contains(&day));
+            assert!((1..=12).contains(&month));
+        }
+        assert_eq!(range.start().year(), -9998);
+        assert_eq!(range.end().year(), 9998);
+        // Wider dates of `Date` stay out of the range of the model
+        #[cfg(feature = "large-dates")]
+        for year in [-100_000, -10_000, 10_000, 100_000] {
+            let date = Date::from_ordinal_date(year, 1).unwrap();
+            assert_eq!(
+                try_convert_date_to_lichta(date, 7.0),
+                Err(LichTaError::DateOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_convert_date_to_lichta_at_the_last_year() {
+        let last = *supported_date_range().end();
+        assert_eq!(
+            try_convert_date_to_lichta(last, 7.0),
+            Ok(convert_date_to_lichta(last, 7.0))
+        );
+        // December of the last year of the model needs the month 11 of the year after it
+        let beyond = last.next_day().unwrap();
+        assert_eq!(
+            try_convert_date_to_lichta(beyond, 7.0),
+            Err(LichTaError::DateOutOfRange)
+        );
+        assert_eq!(
+            try_convert_date_to_lichta(Date::MAX, 7.0),
+            Err(LichTaError::DateOutOfRange)
+        );
+        assert_eq!(
+            try_convert_date_to_lichta(Date::MIN, 7.0),
+            Err(LichTaError::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_try_convert_date_to_lichta_invalid_timezone() {
+        let date = Date::from_calendar_date(2024, time::Month::May, 24).unwrap();
+        for timezone in [f64::NAN, f64::INFINITY, -f64::INFINITY, 24.5, -1e300] {
+            assert_eq!(
+                try_convert_date_to_lichta(date, timezone),
+                Err(LichTaError::InvalidTimezone)
+            );
+        }
+        for date in [
+            *supported_date_range().start(),
+            *supported_date_range().end(),
+        ] {
+            for timezone in [-MAX_TIMEZONE_HOURS, MAX_TIMEZONE_HOURS] {
+                let (day, month, _, _) = try_convert_date_to_lichta(date, timezone).unwrap();
+                assert!((1..=30).contains(&day));
+                assert!((1..=12).contains(&month));
+            }
+        }
+    }
+
+    #[test]
+    fn test_december_31_julian_day() {
+        for year in (-4800..=9998)
+            .step_by(7)
+            .chain([-4713, 0, 1600, 1900, 2000, 2100])
+        {
+            let date = Date::from_calendar_date(year, time::Month::December, 31).unwrap();
+            assert_eq!(december_31_julian_day(year), date.to_julian_day(), "{year}");
+        }
+        let last = Date::from_calendar_date(Date::MAX.year(), time::Month::December, 31);
+        assert_eq!(
+            december_31_julian_day(Date::MAX.year()),
+            last.unwrap().to_julian_day()
+        );
+    }
+
+    #[test]
+    fn test_leap_month_offset_found_in_leap_years() {
+        for year in 1900..2100 {
+            let first_month_11 = get_lunar_month_11(year, 7.0) as i32;
+            let last_month_11 = get_lunar_month_11(year + 1, 7.0) as i32;
+            let leap_month_offset = get_leap_month_offset(first_month_11, 7.0);
+            if last_month_11 - first_month_11 > 365 {
+                assert!(
+                    (1..13).contains(&leap_month_offset),
+                    "{year}: {leap_month_offset}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lunar_month_11_from() {
+        let anchor = Date::from_calendar_date(2025, time::Month::December, 31).unwrap();
+        assert_eq!(
+            lunar_month_11_from(anchor, 7.0),
+            get_lunar_month_11(2025, 7.0)
+        );
+        let month_11 = Date::from_calendar_date(2025, time::Month::December, 20).unwrap();
+        assert_eq!(
+            lunar_month_11_from(anchor, 7.0),
+            f64::from(month_11.to_julian_day())
+        );
+        let anchor = Date::from_calendar_date(2025, time::Month::December, 25).unwrap();
+        assert_eq!(
+            lunar_month_11_from(anchor, 7.0),
+            f64::from(month_11.to_julian_day())
+        );
+    }
+
+    #[test]
+    fn test_convert_datetime_to_lichta() {
+        let utc_7 = time::UtcOffset::from_hms(7, 0, 0).unwrap();
+        let date = |day| Date::from_calendar_date(2025, time::Month::January, day).unwrap();
+        let before_tet = date(28).with_hms(23, 30, 0).unwrap().assume_offset(utc_7);
+        assert_eq!(convert_datetime_to_lichta(before_tet), (29, 12, 2024, 0));
+        let tet = date(29).with_hms(0, 10, 0).unwrap().assume_offset(utc_7);
+        assert_eq!(convert_datetime_to_lichta(tet), (1, 1, 2025, 0));
+
+        // The same instant is still January 28 in UTC
+        let utc = tet.to_offset(time::UtcOffset::UTC);
+        assert_eq!(utc.date(), date(28));
+        assert_eq!(
+            convert_datetime_to_lichta(utc),
+            convert_date_to_lichta(date(28), 0.0)
+        );
+        assert_eq!(
+            convert_datetime_to_lichta(utc.to_offset(utc_7)),
+            (1, 1, 2025, 0)
+        );
+    }
 }
@@ -0,0 +1,185 @@
+//! Parsing of lunar dates written out in Vietnamese, e.g. "ngày 17 tháng Tư năm Giáp Thìn".
+
+use crate::can_chi::{year_can_chi, Can, Chi};
+use crate::{LichTaError, LunarMonthName, NgayTa};
+
+/// Compare two words, ignoring case.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Read a month written as a name, e.g. "Giêng", or as a number, taking the words it needs.
+///
+/// "Mười Một" spans two words, "Mười" alone is month 10.
+fn parse_month<'a>(
+    words: &mut core::iter::Peekable<impl Iterator<Item = &'a str>>,
+) -> Result<i32, LichTaError> {
+    let word = words.next().ok_or(LichTaError::InvalidFormat)?;
+    if let Ok(month) = word.parse::<i32>() {
+        LunarMonthName::try_from(month)?;
+        return Ok(month);
+    }
+    if eq_ignore_case(word, LunarMonthName::Muoi.as_str()) {
+        if words.next_if(|next| eq_ignore_case(next, "Một")).is_some() {
+            return Ok(LunarMonthName::MuoiMot.into());
+        }
+        return Ok(LunarMonthName::Muoi.into());
+    }
+    (1..=12)
+        .find(|&month| {
+            LunarMonthName::try_from(month).is_ok_and(|name| eq_ignore_case(word, name.as_str()))
+        })
+        .ok_or(LichTaError::InvalidFormat)
+}
+
+/// Get the year named `can` `chi` closest to `reference_year`, from 29 years before it to
+/// 30 years after.
+fn year_of_can_chi(can: Can, chi: Chi, reference_year: i32) -> Option<i32> {
+    (reference_year - 29..=reference_year + 30).find(|&year| year_can_chi(year) == (can, chi))
+}
+
+/// Read a year written as a Can Chi name, e.g. "Giáp Thìn", or as a number.
+fn parse_year<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    reference_year: i32,
+) -> Result<i32, LichTaError> {
+    let word = words.next().ok_or(LichTaError::InvalidFormat)?;
+    if let Ok(year) = word.parse() {
+        return Ok(year);
+    }
+    let can = (0..10)
+        .map(Can::from_index)
+        .find(|can| eq_ignore_case(word, can.as_str()))
+        .ok_or(LichTaError::InvalidFormat)?;
+    let word = words.next().ok_or(LichTaError::InvalidFormat)?;
+    let chi = (0..12)
+        .map(Chi::from_index)
+        .find(|chi| eq_ignore_case(word, chi.as_str()))
+        .ok_or(LichTaError::InvalidFormat)?;
+    // A Can and a Chi of different parities never name a year
+    year_of_can_chi(can, chi, reference_year).ok_or(LichTaError::InvalidFormat)
+}
+
+impl NgayTa {
+    /// Parse a lunar date written out in Vietnamese, e.g. "ngày 17 tháng Tư năm Giáp Thìn" or
+    /// "mùng 1 tháng Sáu nhuận".
+    ///
+    /// The form is "ngày" or "mùng" (also "mồng", days 1 to 10 only) with the day, "tháng"
+    /// with the month name or number, "nhuận" for a leap month, then optionally "năm" with
+    /// the year as a Can Chi name or a number. Case is ignored.
+    ///
+    /// A Can Chi name repeats every 60 years, it is read as the year closest to
+    /// `reference_year`, from 29 years before it to 30 years after. Without a year the date is
+    /// in `reference_year`.
+    ///
+    /// The fields are only checked against their ranges, use [`NgayTa::is_valid`] to check the
+    /// date against the calendar.
+    pub fn from_vietnamese(text: &str, reference_year: i32) -> Result<Self, LichTaError> {
+        let mut words = text.split_whitespace().peekable();
+
+        let prefix = words.next().ok_or(LichTaError::InvalidFormat)?;
+        let is_mung = eq_ignore_case(prefix, "mùng") || eq_ignore_case(prefix, "mồng");
+        if !is_mung && !eq_ignore_case(prefix, "ngày") {
+            return Err(LichTaError::InvalidFormat);
+        }
+        let day = words
+            .next()
+            .and_then(|day| day.parse::<i32>().ok())
+            .ok_or(LichTaError::InvalidFormat)?;
+        let max_day = if is_mung { 10 } else { 30 };
+        if !(1..=max_day).contains(&day) {
+            return Err(LichTaError::InvalidFormat);
+        }
+
+        if !words
+            .next()
+            .is_some_and(|word| eq_ignore_case(word, "tháng"))
+        {
+            return Err(LichTaError::InvalidFormat);
+        }
+        let month = parse_month(&mut words)?;
+        let is_leap_month = words
+            .next_if(|word| eq_ignore_case(word, "nhuận"))
+            .is_some();
+
+        let year = match words.next() {
+            Some(word) if eq_ignore_case(word, "năm") => parse_year(&mut words, reference_year)?,
+            Some(_) => return Err(LichTaError::InvalidFormat),
+            None => reference_year,
+        };
+        if words.next().is_some() {
+            return Err(LichTaError::InvalidFormat);
+        }
+        Ok(Self::new(day, month, year, is_leap_month))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vietnamese() {
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 17 tháng Tư năm Giáp Thìn", 2025),
+            Ok(NgayTa::new(17, 4, 2024, false))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("mùng 1 tháng Giêng nhuận", 2025),
+            Ok(NgayTa::new(1, 1, 2025, true))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("Mồng 10 tháng Chạp năm Ất Tỵ", 2000),
+            Ok(NgayTa::new(10, 12, 2025, false))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 5 tháng Năm năm 2024", 2000),
+            Ok(NgayTa::new(5, 5, 2024, false))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 20 tháng Mười Một", 2025),
+            Ok(NgayTa::new(20, 11, 2025, false))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 20 tháng Mười năm Giáp Tý", 2000),
+            Ok(NgayTa::new(20, 10, 1984, false))
+        );
+        // The Giáp Tý year closest to 2025 is 2044, 1984 is 41 years before
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 20 tháng Mười năm Giáp Tý", 2025),
+            Ok(NgayTa::new(20, 10, 2044, false))
+        );
+        assert_eq!(
+            NgayTa::from_vietnamese("ngày 1 tháng 6 nhuận năm Ất Tỵ", 2025),
+            Ok(NgayTa::new(1, 6, 2025, true))
+        );
+    }
+
+    #[test]
+    fn test_from_vietnamese_errors() {
+        let parse = |text| NgayTa::from_vietnamese(text, 2025);
+        // "mùng" is only for the first ten days
+        assert_eq!(
+            parse("mùng 15 tháng Giêng"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("ngày 31 tháng Giêng"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(parse("ngày 1 tháng 13"), Err(LichTaError::InvalidMonth(13)));
+        assert_eq!(
+            parse("ngày 1 tháng Mười Ba"),
+            Err(LichTaError::InvalidFormat)
+        );
+        // Giáp goes with even branches only
+        assert_eq!(
+            parse("ngày 1 tháng Giêng năm Giáp Sửu"),
+            Err(LichTaError::InvalidFormat)
+        );
+        assert_eq!(parse("ngày 1 Giêng"), Err(LichTaError::InvalidFormat));
+        assert_eq!(parse(""), Err(LichTaError::InvalidFormat));
+    }
+}
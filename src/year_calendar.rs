@@ -0,0 +1,199 @@
+//! The [`YearCalendar`] struct and its associated `impl`s.
+
+use alloc::vec::Vec;
+
+use crate::can_chi::day_can_chi;
+use crate::holiday::lunar_holiday;
+use crate::solar_term::get_solar_term;
+use crate::{Can, Chi, Converter, Date, Holiday, NgayTa, SolarTerm};
+
+/// Everything a printed calendar shows for one Gregorian day.
+#[derive(Clone, Copy, Debug)]
+pub struct CalendarDay {
+    date: Date,
+    ngay_ta: NgayTa,
+    can_chi: (Can, Chi),
+    solar_term_start: Option<SolarTerm>,
+    holiday: Option<Holiday>,
+}
+
+impl CalendarDay {
+    pub fn date(&self) -> Date {
+        self.date
+    }
+    pub fn ngay_ta(&self) -> NgayTa {
+        self.ngay_ta
+    }
+    /// Can Chi of the day.
+    pub fn can_chi(&self) -> (Can, Chi) {
+        self.can_chi
+    }
+    /// Solar term beginning on this day, if any.
+    pub fn solar_term_start(&self) -> Option<SolarTerm> {
+        self.solar_term_start
+    }
+    /// Holiday falling on this day, if any.
+    pub fn holiday(&self) -> Option<Holiday> {
+        self.holiday
+    }
+}
+
+/// Every day of a Gregorian year with its LichTa data.
+#[derive(Clone, Debug)]
+pub struct YearCalendar {
+    year: i32,
+    days: Vec<CalendarDay>,
+}
+
+impl YearCalendar {
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    /// Days of the year, from January 1 to December 31.
+    pub fn days(&self) -> &[CalendarDay] {
+        &self.days
+    }
+    pub fn iter(&self) -> core::slice::Iter<'_, CalendarDay> {
+        self.days.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a YearCalendar {
+    type Item = &'a CalendarDay;
+    type IntoIter = core::slice::Iter<'a, CalendarDay>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Build the calendar of a Gregorian year in a single pass.
+///
+/// A lunar month has at least 29 days, so a full conversion is only needed from the 29th of
+/// each month; every other day is the next day of the same month.
+///
+/// Parameters:
+/// - `year`: Gregorian year.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Panics if `year` is outside the range supported by [`Date`].
+pub fn year_calendar(year: i32, timezone: f64) -> YearCalendar {
+    let mut date = Date::from_ordinal_date(year, 1).expect("Invalid date for year");
+    let mut converter = Converter::new(timezone);
+    let mut previous: Option<NgayTa> = None;
+    let mut previous_term = get_solar_term(date.to_julian_day() - 1, timezone);
+    let mut days = Vec::with_capacity(366);
+
+    while date.year() == year {
+        let ngay_ta = match previous {
+            Some(p) if p.day() < 29 => {
+                NgayTa::new(p.day() + 1, p.month(), p.year(), p.is_leap_month())
+            }
+            _ => converter.convert(date),
+        };
+        let term = get_solar_term(date.to_julian_day(), timezone);
+        days.push(CalendarDay {
+            date,
+            ngay_ta,
+            can_chi: day_can_chi(date),
+            solar_term_start: (term != previous_term).then_some(term),
+            holiday: lunar_holiday(&ngay_ta),
+        });
+        previous = Some(ngay_ta);
+        previous_term = term;
+        match date.next_day() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+
+    YearCalendar { year, days }
+}
+
+/// Get the LichTa date of every occurrence of a weekday in a Gregorian year, e.g. the lunar
+/// date of each Sunday.
+///
+/// Parameters:
+/// - `year`: Gregorian year.
+/// - `weekday`: Day of the week.
+/// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+///
+/// Returns: The 52 or 53 dates, in order.
+///
+/// Panics if `year` is outside the range supported by [`Date`].
+pub fn lunar_dates_for_weekday(year: i32, weekday: time::Weekday, timezone: f64) -> Vec<NgayTa> {
+    let mut date = Date::from_ordinal_date(year, 1).expect("Invalid date for year");
+    while date.weekday() != weekday {
+        date = date.next_day().expect("Invalid date for year");
+    }
+    let mut converter = Converter::new(timezone);
+    let mut dates = Vec::with_capacity(53);
+    while date.year() == year {
+        dates.push(converter.convert(date));
+        match date.checked_add(time::Duration::WEEK) {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    dates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solar_term_starting;
+    use time::Month;
+
+    #[test]
+    fn test_year_calendar_matches_per_day_calls() {
+        for year in [2024, 2025] {
+            let calendar = year_calendar(year, 7.0);
+            assert_eq!(calendar.year(), year);
+            assert_eq!(calendar.days().len(), if year == 2024 { 366 } else { 365 });
+            for day in &calendar {
+                let expected = NgayTa::from_date(day.date(), 7.0);
+                assert_eq!(day.ngay_ta().day(), expected.day());
+                assert_eq!(day.ngay_ta().month(), expected.month());
+                assert_eq!(day.ngay_ta().year(), expected.year());
+                assert_eq!(day.ngay_ta().is_leap_month(), expected.is_leap_month());
+                assert_eq!(day.can_chi(), day_can_chi(day.date()));
+                assert_eq!(day.solar_term_start(), solar_term_starting(day.date(), 7.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_year_calendar_markers() {
+        let calendar = year_calendar(2025, 7.0);
+        let tet = Date::from_calendar_date(2025, Month::January, 29).unwrap();
+        let day = calendar.iter().find(|d| d.date() == tet).unwrap();
+        assert_eq!(day.holiday(), Some(Holiday::TetNguyenDan));
+        let terms = calendar.iter().filter(|d| d.solar_term_start().is_some());
+        assert_eq!(terms.count(), 24);
+    }
+
+    #[test]
+    fn test_lunar_dates_for_weekday() {
+        // 2025 starts on a Wednesday, 2024 on a Monday and has 366 days
+        let sundays = lunar_dates_for_weekday(2025, time::Weekday::Sunday, 7.0);
+        assert_eq!(sundays.len(), 52);
+        let first_sunday = Date::from_calendar_date(2025, Month::January, 5).unwrap();
+        assert_eq!(sundays[0], NgayTa::from_date(first_sunday, 7.0));
+        assert_eq!(
+            lunar_dates_for_weekday(2025, time::Weekday::Wednesday, 7.0).len(),
+            53
+        );
+        assert_eq!(
+            lunar_dates_for_weekday(2024, time::Weekday::Tuesday, 7.0).len(),
+            53
+        );
+
+        let calendar = year_calendar(2025, 7.0);
+        let expected: Vec<NgayTa> = calendar
+            .iter()
+            .filter(|day| day.date().weekday() == time::Weekday::Sunday)
+            .map(CalendarDay::ngay_ta)
+            .collect();
+        assert_eq!(sundays, expected);
+    }
+}
@@ -0,0 +1,143 @@
+//! The [`Zodiac`] animals (con giáp) of the earthly branches.
+
+use crate::can_chi::day_can_chi;
+use crate::{Chi, LichTaError, NgayTa};
+
+/// Animal (con giáp) associated with each [`Chi`], in the Vietnamese tradition: Mèo for Mão
+/// rather than the rabbit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Zodiac {
+    Chuot,
+    Trau,
+    Ho,
+    Meo,
+    Rong,
+    Ran,
+    Ngua,
+    De,
+    Khi,
+    Ga,
+    Cho,
+    Lon,
+}
+
+impl Zodiac {
+    const ALL: [Zodiac; 12] = [
+        Zodiac::Chuot,
+        Zodiac::Trau,
+        Zodiac::Ho,
+        Zodiac::Meo,
+        Zodiac::Rong,
+        Zodiac::Ran,
+        Zodiac::Ngua,
+        Zodiac::De,
+        Zodiac::Khi,
+        Zodiac::Ga,
+        Zodiac::Cho,
+        Zodiac::Lon,
+    ];
+
+    /// Vietnamese name, e.g. "Chuột".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Zodiac::Chuot => "Chuột",
+            Zodiac::Trau => "Trâu",
+            Zodiac::Ho => "Hổ",
+            Zodiac::Meo => "Mèo",
+            Zodiac::Rong => "Rồng",
+            Zodiac::Ran => "Rắn",
+            Zodiac::Ngua => "Ngựa",
+            Zodiac::De => "Dê",
+            Zodiac::Khi => "Khỉ",
+            Zodiac::Ga => "Gà",
+            Zodiac::Cho => "Chó",
+            Zodiac::Lon => "Lợn",
+        }
+    }
+    /// Earthly branch of the animal.
+    pub fn chi(self) -> Chi {
+        Chi::from_index(self as i32)
+    }
+    /// Get the animal clashing (xung) with this one, 6 positions away: Chuột and Ngựa, Trâu
+    /// and Dê and so on.
+    pub fn opposing(&self) -> Zodiac {
+        Zodiac::ALL[(*self as usize + 6) % 12]
+    }
+}
+
+impl From<Chi> for Zodiac {
+    fn from(chi: Chi) -> Self {
+        Zodiac::ALL[chi.index()]
+    }
+}
+
+impl Chi {
+    /// Animal of the branch, e.g. Chuột for Tý.
+    pub fn zodiac(self) -> Zodiac {
+        self.into()
+    }
+}
+
+impl NgayTa {
+    /// Get the ages (tuổi) clashing with the day, which are warned off events on it.
+    ///
+    /// These are the other three branches of the day Chi in the four clashes (tứ hành xung):
+    /// Tý, Ngọ, Mão and Dậu clash with each other, as do Dần, Thân, Tỵ and Hợi, and Thìn, Tuất,
+    /// Sửu and Mùi. The direct clash (lục xung), 6 positions away, comes first.
+    ///
+    /// Parameters:
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    ///
+    /// Returns an error if the date does not exist in the LichTa calendar.
+    pub fn conflicting_ages(&self, timezone: f64) -> Result<[Chi; 3], LichTaError> {
+        let (_, day_chi) = day_can_chi(self.to_date(timezone)?);
+        let opposing = day_chi.zodiac().opposing().chi();
+        Ok([
+            opposing,
+            Chi::from_index(day_chi.index() as i32 + 3),
+            Chi::from_index(day_chi.index() as i32 + 9),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_can_chi;
+
+    #[test]
+    fn test_zodiac() {
+        assert_eq!(Chi::Ty.zodiac(), Zodiac::Chuot);
+        assert_eq!(Chi::Mao.zodiac(), Zodiac::Meo);
+        assert_eq!(Chi::Hoi.zodiac(), Zodiac::Lon);
+        assert_eq!(year_can_chi(2024).1.zodiac().as_str(), "Rồng");
+        for index in 0..12 {
+            let chi = Chi::from_index(index);
+            assert_eq!(chi.zodiac().chi(), chi);
+        }
+    }
+
+    #[test]
+    fn test_opposing() {
+        assert_eq!(Zodiac::Chuot.opposing(), Zodiac::Ngua);
+        assert_eq!(Zodiac::Trau.opposing(), Zodiac::De);
+        assert_eq!(Zodiac::Lon.opposing(), Zodiac::Ran);
+        for index in 0..12 {
+            let zodiac = Chi::from_index(index).zodiac();
+            assert_eq!(zodiac.opposing().opposing(), zodiac);
+        }
+    }
+
+    #[test]
+    fn test_conflicting_ages() {
+        // Tết Giáp Thìn was a Thìn day
+        let tet = NgayTa::new(1, 1, 2024, false);
+        assert_eq!(
+            tet.conflicting_ages(7.0),
+            Ok([Chi::Tuat, Chi::Mui, Chi::Suu])
+        );
+        assert!(NgayTa::new(30, 4, 2024, false)
+            .conflicting_ages(7.0)
+            .is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! The [`ZonedNgayTa`] struct, a [`NgayTa`] bound to its timezone for date arithmetic.
+
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use time::Duration;
+
+use crate::{Date, LichTaError, NgayTa};
+
+/// A [`NgayTa`] with the timezone it was converted in, so days can be added to it.
+///
+/// Adding a [`Duration`] goes through the Gregorian calendar, like [`Date`] does: only the
+/// whole days of the duration count, and the result is numbered in the same timezone.
+///
+/// ```
+/// use lich_ta::{NgayTa, ZonedNgayTa};
+/// use time::Duration;
+///
+/// let tet = ZonedNgayTa::new(NgayTa::new(1, 1, 2025, false), 7.0);
+/// assert_eq!((tet + Duration::days(14)).ngay_ta(), NgayTa::new(15, 1, 2025, false));
+/// assert_eq!((tet - Duration::days(1)).ngay_ta(), NgayTa::new(29, 12, 2024, false));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZonedNgayTa {
+    ngay_ta: NgayTa,
+    timezone: f64,
+}
+
+impl ZonedNgayTa {
+    pub fn new(ngay_ta: NgayTa, timezone: f64) -> Self {
+        Self { ngay_ta, timezone }
+    }
+    /// Convert a Gregorian date and keep its timezone.
+    ///
+    /// Parameters:
+    /// - `date`: Gregorian date.
+    /// - `timezone`: Local timezone offset from UTC in hours (e.g., -5 for EST).
+    pub fn from_date(date: Date, timezone: f64) -> Self {
+        Self::new(NgayTa::from_date(date, timezone), timezone)
+    }
+}
+
+impl ZonedNgayTa {
+    pub fn ngay_ta(&self) -> NgayTa {
+        self.ngay_ta
+    }
+    pub fn timezone(&self) -> f64 {
+        self.timezone
+    }
+    /// Convert back to the Gregorian calendar, same as [`NgayTa::to_date`].
+    pub fn to_date(&self) -> Result<Date, LichTaError> {
+        self.ngay_ta.to_date(self.timezone)
+    }
+    /// Add a duration, returning `None` if the date does not exist or the result is outside
+    /// [`supported_date_range`](crate::supported_date_range).
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let date = self.to_date().ok()?.checked_add(duration)?;
+        let ngay_ta = NgayTa::try_from_date(date, self.timezone).ok()?;
+        Some(Self::new(ngay_ta, self.timezone))
+    }
+    /// Subtract a duration, returning `None` like [`ZonedNgayTa::checked_add`].
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_add(duration.checked_neg()?)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the date does not exist or the result is outside
+/// [`supported_date_range`](crate::supported_date_range).
+impl Add<Duration> for ZonedNgayTa {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        self.checked_add(duration)
+            .expect("Invalid date for ZonedNgayTa + Duration")
+    }
+}
+
+/// # Panics
+///
+/// Panics like the [`Add`] implementation.
+impl Sub<Duration> for ZonedNgayTa {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration)
+            .expect("Invalid date for ZonedNgayTa - Duration")
+    }
+}
+
+impl AddAssign<Duration> for ZonedNgayTa {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl SubAssign<Duration> for ZonedNgayTa {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub() {
+        // Month 6 of 2025 is followed by a leap month 6
+        let zoned = ZonedNgayTa::new(NgayTa::new(30, 6, 2025, false), 7.0);
+        let next = zoned + Duration::days(1);
+        assert_eq!(next.ngay_ta(), NgayTa::new(1, 6, 2025, true));
+        assert_eq!(next.timezone(), 7.0);
+        assert_eq!(next - Duration::days(1), zoned);
+
+        let mut zoned = ZonedNgayTa::from_date(
+            Date::from_calendar_date(2025, time::Month::January, 28).unwrap(),
+            7.0,
+        );
+        zoned += Duration::days(1);
+        assert_eq!(zoned.ngay_ta(), NgayTa::new(1, 1, 2025, false));
+        zoned -= Duration::days(29);
+        assert_eq!(zoned.ngay_ta(), NgayTa::new(1, 12, 2024, false));
+        // Hours short of a day are dropped
+        assert_eq!(zoned + Duration::hours(23), zoned);
+    }
+
+    #[test]
+    fn test_checked_add() {
+        // Month 4 of 2024 has 29 days
+        let invalid = ZonedNgayTa::new(NgayTa::new(30, 4, 2024, false), 7.0);
+        assert_eq!(invalid.checked_add(Duration::days(1)), None);
+        assert_eq!(invalid.to_date(), invalid.ngay_ta().to_date(7.0));
+
+        let zoned = ZonedNgayTa::new(NgayTa::new(1, 1, 2025, false), 7.0);
+        assert_eq!(zoned.checked_add(Duration::MAX), None);
+        assert_eq!(zoned.checked_sub(Duration::MIN), None);
+        assert_eq!(
+            zoned
+                .checked_sub(Duration::days(1))
+                .map(|zoned| zoned.ngay_ta()),
+            Some(NgayTa::new(29, 12, 2024, false))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid date")]
+    fn test_add_invalid_date() {
+        let _ = ZonedNgayTa::new(NgayTa::new(30, 4, 2024, false), 7.0) + Duration::days(1);
+    }
+}